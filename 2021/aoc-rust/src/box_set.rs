@@ -0,0 +1,177 @@
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+    ops::Range,
+};
+
+/// Axis-aligned box of integer cubes, each range half-open.
+pub type Cuboid = (Range<i32>, Range<i32>, Range<i32>);
+
+pub fn volume(c: &Cuboid) -> i64 {
+    let length = |r: &Range<i32>| max(r.end as i64 - r.start as i64, 0);
+    length(&c.0) * length(&c.1) * length(&c.2)
+}
+
+/// The common cubes of both cuboids, `None` when there are none.
+pub fn intersection(a: &Cuboid, b: &Cuboid) -> Option<Cuboid> {
+    let overlap = |a: &Range<i32>, b: &Range<i32>| {
+        Some(max(a.start, b.start)..min(a.end, b.end)).filter(|r| !r.is_empty())
+    };
+    Some((
+        overlap(&a.0, &b.0)?,
+        overlap(&a.1, &b.1)?,
+        overlap(&a.2, &b.2)?,
+    ))
+}
+
+/// Set of cubes kept as a signed sum of cuboids (inclusion-exclusion): the
+/// weights of the cuboids containing a cube sum to 1 for the cubes of the set
+/// and to 0 for all others.
+/// Optionally every cuboid is clamped to a region first, so cubes outside of
+/// it are never added.
+#[derive(Debug, Clone, Default)]
+pub struct BoxSet {
+    terms: HashMap<Cuboid, i64>,
+    clamp: Option<Cuboid>,
+}
+
+impl BoxSet {
+    pub fn new() -> BoxSet {
+        BoxSet::default()
+    }
+
+    /// Drops the cubes outside of `region` and never adds them again.
+    pub fn clamped_to(mut self, region: Cuboid) -> BoxSet {
+        self.intersect(&region);
+        self.clamp = Some(region);
+        self
+    }
+
+    /// Adds the cubes of `c` not in the set yet.
+    pub fn union(&mut self, c: &Cuboid) {
+        let Some(c) = self.clamped(c) else {
+            return;
+        };
+        self.subtract(&c);
+        self.add_term(c, 1);
+    }
+
+    /// Removes the cubes of `c` from the set.
+    pub fn subtract(&mut self, c: &Cuboid) {
+        let Some(c) = self.clamped(c) else {
+            return;
+        };
+        let overlaps = self
+            .terms
+            .iter()
+            .filter_map(|(term, weight)| intersection(term, &c).map(|i| (i, -weight)))
+            .collect::<Vec<_>>();
+        for (cuboid, weight) in overlaps {
+            self.add_term(cuboid, weight);
+        }
+    }
+
+    /// Keeps only the cubes of the set inside `c`.
+    pub fn intersect(&mut self, c: &Cuboid) {
+        self.terms = self
+            .terms
+            .drain()
+            .filter_map(|(term, weight)| intersection(&term, c).map(|i| (i, weight)))
+            .fold(HashMap::new(), |mut terms, (cuboid, weight)| {
+                *terms.entry(cuboid).or_insert(0) += weight;
+                terms
+            });
+        self.terms.retain(|_, weight| *weight != 0);
+    }
+
+    pub fn volume(&self) -> i64 {
+        self.terms
+            .iter()
+            .map(|(cuboid, weight)| weight * volume(cuboid))
+            .sum()
+    }
+
+    fn clamped(&self, c: &Cuboid) -> Option<Cuboid> {
+        match &self.clamp {
+            Some(region) => intersection(c, region),
+            None => Some(c.clone()).filter(|c| volume(c) > 0),
+        }
+    }
+
+    fn add_term(&mut self, cuboid: Cuboid, weight: i64) {
+        let total = self.terms.entry(cuboid.clone()).or_insert(0);
+        *total += weight;
+        if *total == 0 {
+            self.terms.remove(&cuboid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_box_set {
+    use super::*;
+
+    #[test]
+    fn test_volume_and_intersection() {
+        let a = (0..3, 0..3, 0..3);
+        let b = (1..5, 0..3, 0..3);
+        let c = (-1..2, -300..-200, -300..-200);
+
+        assert_eq!(volume(&a), 27);
+        assert_eq!(volume(&b), 36);
+        assert_eq!(volume(&c), 30000);
+        assert_eq!(volume(&(2..2, 0..3, 0..3)), 0);
+        assert_eq!(intersection(&a, &b), Some((1..3, 0..3, 0..3)));
+        assert_eq!(intersection(&a, &c), None);
+        assert_eq!(intersection(&a, &(3..4, 0..3, 0..3)), None);
+    }
+
+    #[test]
+    fn test_union_and_subtract() {
+        let mut set = BoxSet::new();
+
+        set.union(&(0..3, 0..3, 0..3));
+        set.union(&(2..5, 2..5, 2..5));
+        assert_eq!(set.volume(), 53);
+        set.union(&(1..2, 1..2, 1..2));
+        assert_eq!(set.volume(), 53);
+
+        set.subtract(&(2..5, 2..5, 2..5));
+        assert_eq!(set.volume(), 26);
+        set.subtract(&(0..1, 0..1, 0..1));
+        assert_eq!(set.volume(), 25);
+        set.subtract(&(4..5, 4..5, 4..5));
+        assert_eq!(set.volume(), 25);
+
+        set.subtract(&(-10..10, -10..10, -10..10));
+        assert_eq!(set.volume(), 0);
+        assert!(set.terms.is_empty());
+    }
+
+    #[test]
+    fn test_intersect_and_clamp() {
+        let mut set = BoxSet::new();
+        set.union(&(0..3, 0..3, 0..3));
+        set.union(&(2..5, 2..5, 2..5));
+
+        set.intersect(&(2..3, 2..3, 0..10));
+        assert_eq!(set.volume(), 5);
+        set.intersect(&(10..20, 10..20, 10..20));
+        assert_eq!(set.volume(), 0);
+        assert!(set.terms.is_empty());
+
+        let mut clamped = BoxSet::new().clamped_to((0..2, 0..2, 0..2));
+        clamped.union(&(-5..5, -5..5, -5..5));
+        clamped.union(&(10..20, 10..20, 10..20));
+        assert_eq!(clamped.volume(), 8);
+        clamped.subtract(&(1..9, 1..9, 1..9));
+        assert_eq!(clamped.volume(), 7);
+
+        let mut set = BoxSet::new();
+        set.union(&(0..3, 0..3, 0..3));
+        let mut clamped = set.clamped_to((2..5, 2..5, 2..5));
+        assert_eq!(clamped.volume(), 1);
+        clamped.union(&(0..10, 0..10, 0..10));
+        assert_eq!(clamped.volume(), 27);
+    }
+}
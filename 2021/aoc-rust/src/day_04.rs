@@ -6,6 +6,8 @@ const BOARD_WIDTH: usize = 5;
 const BOARD_HEIGHT: usize = BOARD_WIDTH;
 const BOARD_SIZE: usize = BOARD_WIDTH * BOARD_HEIGHT;
 
+aoc_commons::solution!(2021, 4, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     let (numbers, boards) = parse_input(input);
     let mut hit_masks: Vec<Vec<bool>> = vec![vec![false; BOARD_SIZE]; boards.len()];
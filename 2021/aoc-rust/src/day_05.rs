@@ -4,6 +4,8 @@ use rustc_hash::FxHashMap;
 use crate::utils::sign;
 
 
+aoc_commons::solution!(2021, 5, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     let pairs = parse_pairs(input);
     let non_diagonal_pairs = pairs
@@ -1,7 +1,9 @@
-use rustc_hash::FxHashMap;
+use aoc_commons::memo::Memo;
 
 const LIFETIME_DAYS: i32 = 7;
 
+aoc_commons::solution!(2021, 6, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     solve(&parse(input), 80)
 }
@@ -11,30 +13,23 @@ pub fn second_part(input: &str) -> i64 {
 }
 
 fn solve(states: &[i32], n_days: i32) -> i64 {
-    let mut memoization: FxHashMap<i32, i64> = FxHashMap::default();
+    let mut memo = Memo::new();
 
     states
         .iter()
-        .map(|state| fish_count(n_days + (LIFETIME_DAYS + 2) - (state + 1), &mut memoization))
+        .map(|state| fish_count(n_days + (LIFETIME_DAYS + 2) - (state + 1), &mut memo))
         .sum::<i64>()
 }
 
-fn fish_count(days: i32, memoization: &mut FxHashMap<i32, i64>) -> i64 {
-    let memoized = memoization.get(&days);
-
-    if let Some(memoized_value) = memoized {
-        *memoized_value
-    } else {
-        let new_value = (0..=(days - (LIFETIME_DAYS + 2)))
+fn fish_count(days: i32, memo: &mut Memo<i32, i64>) -> i64 {
+    memo.get_or_compute(days, |memo, &days| {
+        (0..=(days - (LIFETIME_DAYS + 2)))
             .rev()
             .step_by(LIFETIME_DAYS as usize)
-            .map(|days_left| fish_count(days_left, memoization))
+            .map(|days_left| fish_count(days_left, memo))
             .sum::<i64>()
-            + 1;
-
-        memoization.insert(days, new_value);
-        new_value
-    }
+            + 1
+    })
 }
 
 fn parse(input: &str) -> Vec<i32> {
@@ -45,19 +40,20 @@ fn parse(input: &str) -> Vec<i32> {
         .collect::<Vec<_>>()
 }
 
+#[cfg(test)]
 mod tests {
 
-    use super::{first_part, fish_count, second_part};
-    use rustc_hash::FxHashMap;
+    use super::{first_part, fish_count, second_part, Memo};
+    use aoc_commons::inputs::{example, real};
 
     #[test]
     fn test_fish_count() {
-        assert_eq!(fish_count(0, &mut FxHashMap::default()), 1);
-        assert_eq!(fish_count(5, &mut FxHashMap::default()), 1);
-        assert_eq!(fish_count(6, &mut FxHashMap::default()), 1);
-        assert_eq!(fish_count(7, &mut FxHashMap::default()), 1);
-        assert_eq!(fish_count(8, &mut FxHashMap::default()), 1);
-        assert_eq!(fish_count(9, &mut FxHashMap::default()), 2);
+        assert_eq!(fish_count(0, &mut Memo::new()), 1);
+        assert_eq!(fish_count(5, &mut Memo::new()), 1);
+        assert_eq!(fish_count(6, &mut Memo::new()), 1);
+        assert_eq!(fish_count(7, &mut Memo::new()), 1);
+        assert_eq!(fish_count(8, &mut Memo::new()), 1);
+        assert_eq!(fish_count(9, &mut Memo::new()), 2);
     }
 
     #[test]
@@ -72,20 +68,17 @@ mod tests {
 
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("../inputs/06_example")), 5934);
-        assert_eq!(
-            second_part(include_str!("../inputs/06_example")),
-            26984457539
-        );
+        assert_eq!(first_part(&example(2021, 6, 1)), 5934);
+        assert_eq!(second_part(&example(2021, 6, 1)), 26984457539);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/06.in")), 387413);
+        assert_eq!(first_part(&real(2021, 6)), 387413);
     }
 
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/06.in")), 1738377086345);
+        assert_eq!(second_part(&real(2021, 6)), 1738377086345);
     }
 }
@@ -1,5 +1,7 @@
 
 
+aoc_commons::solution!(2021, 7, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     let crab_positions = parse_input(input);
     // solve_iteratively(&crab_positions, difference_distance)
@@ -74,26 +76,28 @@ fn parse_input(input: &str) -> Vec<i32> {
         .collect()
 }
 
+#[cfg(test)]
 mod test_day_07 {
+    use aoc_commons::inputs::{example, real};
     use super::{first_part, second_part};
 
     #[test]
     fn test_example_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/07_example")), 37);
+        assert_eq!(first_part(&example(2021, 7, 1)), 37);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/07.in")), 343468);
+        assert_eq!(first_part(&real(2021, 7)), 343468);
     }
 
     #[test]
     fn test_example_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/07_example")), 168);
+        assert_eq!(second_part(&example(2021, 7, 1)), 168);
     }
 
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/07.in")), 96086265);
+        assert_eq!(second_part(&real(2021, 7)), 96086265);
     }
 }
@@ -6,6 +6,8 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 const N_SEGMENTS: usize = 7;
 const ALL_CHARS: [char; N_SEGMENTS] = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
 
+aoc_commons::solution!(2021, 8, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     let problems = parse(input);
 
@@ -233,8 +235,10 @@ fn parse(input: &str) -> Vec<Problem> {
         .collect::<Vec<Problem>>()
 }
 
+#[cfg(test)]
 mod test_day_08 {
 
+    use aoc_commons::inputs::{example, real};
     use std::collections::HashSet;
 
     use crate::day_08::build_initial_candidates;
@@ -243,18 +247,18 @@ mod test_day_08 {
 
     #[test]
     fn test_problem_parsing() {
-        let problems = parse(include_str!("../inputs/08_example"));
+        let problems = parse(&example(2021, 8, 1));
         assert_eq!(problems.len(), 10);
     }
 
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("../inputs/08_example")), 26);
+        assert_eq!(first_part(&example(2021, 8, 1)), 26);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/08.in")), 493);
+        assert_eq!(first_part(&real(2021, 8)), 493);
     }
 
     #[test]
@@ -305,10 +309,10 @@ mod test_day_08 {
 
     #[test]
     fn test_example_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/08_example")), 61229);
+        assert_eq!(second_part(&example(2021, 8, 1)), 61229);
     }
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/08.in")), 1010460);
+        assert_eq!(second_part(&real(2021, 8)), 1010460);
     }
 }
@@ -1,84 +1,40 @@
-use std::collections::{HashSet, VecDeque};
-
+use aoc_commons::error::SolveError;
+use aoc_commons::grid::{Grid, Point};
 use rustc_hash::FxHashMap;
 
-use crate::grid::{Grid, Point};
-
 const WALL_VALUE: i32 = 9;
 
-pub fn first_part(input: &str) -> i32 {
-    let g = parse(input);
+aoc_commons::solution!(2021, 9, first_part, second_part);
+
+pub fn first_part(input: &str) -> Result<i32, SolveError> {
+    let g = parse(input)?;
 
-    g.iter()
+    Ok(g.iter()
         .filter(|(p, v)| is_local_minimum(p, &g))
         .map(|(_, v)| v + 1)
-        .sum::<i32>()
+        .sum::<i32>())
 }
 
-pub fn second_part(input: &str) -> i32 {
-    let g = parse(input);
-    let mut dfs_stack = g
-        .iter_points()
-        .filter(|p| is_local_minimum(p, &g))
-        .collect::<VecDeque<_>>();
-
-    let mut visited = dfs_stack.iter().copied().collect::<HashSet<_>>();
-    let mut basin_ids = dfs_stack
-        .iter()
-        .copied()
-        .enumerate()
-        .map(|(i, p)| (p, i))
-        .collect::<FxHashMap<_, _>>();
-
-    while !dfs_stack.is_empty() {
-        let current: Point = dfs_stack.pop_back().expect("is not empty");
-        for (ad_p, ad_v) in g.four_neighborhood_at(&current) {
-            if !visited.contains(&ad_p) && (*ad_v < WALL_VALUE) {
-                visited.insert(ad_p);
-                dfs_stack.push_back(ad_p);
-
-                let current_basin_id = *basin_ids.get(&current).expect("must have basin id");
-
-                if let Some(ad_basin_id) = basin_ids.get(&ad_p) {
-                    if *ad_basin_id != current_basin_id {
-                        let dummy = *ad_basin_id; // ask: why can't I put this into the argument
-                        re_assign_basin(&mut basin_ids, dummy, current_basin_id);
-                    }
-                } else {
-                    basin_ids.insert(ad_p, current_basin_id);
-                }
-            }
-        }
-    }
+pub fn second_part(input: &str) -> Result<i32, SolveError> {
+    let g = parse(input)?;
+    let basin_ids = g.components(|v| *v < WALL_VALUE);
 
-    multiply_top_three_basins(&basin_ids)
+    Ok(multiply_top_three_basins(&basin_ids))
 }
 
-fn multiply_top_three_basins(basin_ids: &FxHashMap<Point, usize>) -> i32 {
+fn multiply_top_three_basins(basin_ids: &Grid<Option<usize>>) -> i32 {
     let mut counter: FxHashMap<usize, usize> = FxHashMap::default();
 
-    for (p, basin_id) in basin_ids.iter() {
-        if let Some(count) = counter.get(basin_id) {
-            counter.insert(*basin_id, *count + 1);
-        } else {
-            counter.insert(*basin_id, 1);
-        }
+    for basin_id in basin_ids.iter_values().flatten() {
+        *counter.entry(*basin_id).or_default() += 1;
     }
 
-    let mut sizes: Vec<usize> = counter.iter().map(|(k, v)| *v).collect::<Vec<_>>();
+    let mut sizes: Vec<usize> = counter.into_values().collect::<Vec<_>>();
 
     sizes.sort();
     sizes.iter().rev().take(3).product::<usize>() as i32
 }
 
-fn re_assign_basin(basin_ids: &mut FxHashMap<Point, usize>, from: usize, to: usize) {
-    basin_ids.iter_mut().for_each(|(_, v)| {
-        if v == &from {
-            *v = to
-        }
-    });
-}
-
 fn is_local_minimum(p: &Point, g: &Grid<i32>) -> bool {
     if let Ok(v) = g.at(p) {
         g.four_neighborhood_at(p).iter().all(|(_, n)| *n > v)
@@ -87,38 +43,32 @@ fn is_local_minimum(p: &Point, g: &Grid<i32>) -> bool {
     }
 }
 
-fn parse(input: &str) -> Grid<i32> {
-    input
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_string().parse::<i32>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Grid<_>>()
+fn parse(input: &str) -> Result<Grid<i32>, SolveError> {
+    Grid::parse_digits(input)
 }
 
+#[cfg(test)]
 mod tests_day_09 {
 
     use super::*;
+    use aoc_commons::inputs::{example, real};
 
     #[test]
     fn test_first_part_example() {
-        assert_eq!(first_part(include_str!("../inputs/09_example")), 15);
+        assert_eq!(first_part(&example(2021, 9, 1)), Ok(15));
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/09.in")), 528)
+        assert_eq!(first_part(&real(2021, 9)), Ok(528))
     }
 
     #[test]
     fn test_second_part_example() {
-        assert_eq!(second_part(include_str!("../inputs/09_example")), 1134);
+        assert_eq!(second_part(&example(2021, 9, 1)), Ok(1134));
     }
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/09.in")), 920448);
+        assert_eq!(second_part(&real(2021, 9)), Ok(920448));
     }
 }
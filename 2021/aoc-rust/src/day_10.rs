@@ -1,5 +1,7 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 
+aoc_commons::solution!(2021, 10, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     input
         .lines()
@@ -81,6 +83,7 @@ fn get_autocomplete_map() -> FxHashMap<char, (char, i64)> {
 
 #[cfg(test)]
 mod tests_day_10 {
+    use aoc_commons::inputs::real;
     use super::*;
 
     const EXAMPLE_INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
@@ -100,7 +103,7 @@ mod tests_day_10 {
     }
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/10.in")), 323691);
+        assert_eq!(first_part(&real(2021, 10)), 323691);
     }
 
     #[test]
@@ -118,6 +121,6 @@ mod tests_day_10 {
     
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/10.in")), 2858785164);
+        assert_eq!(second_part(&real(2021, 10)), 2858785164);
     }
 }
@@ -1,91 +1,79 @@
-use std::collections::VecDeque;
+use std::collections::HashSet;
 
-use crate::grid::{Grid, Point};
+use aoc_commons::error::SolveError;
+use aoc_commons::flood::flood_fill;
+use aoc_commons::grid::{Grid, Point};
 
 const N_STEPS_FIRST_PART: usize = 100;
 
-pub fn first_part(input: &str) -> i32 {
-    let mut octopus_map = parse(input);
+aoc_commons::solution!(2021, 11, first_part, second_part);
+
+pub fn first_part(input: &str) -> Result<i32, SolveError> {
+    let mut octopus_map = parse(input)?;
     let mut n_flashes = 0;
 
     for (i, _) in std::iter::repeat(()).enumerate() {
-        n_flashes += compute_flash_map(&mut octopus_map)
-            .iter()
-            .filter(|(_, &val)| val)
-            .count() as i32;
+        n_flashes += compute_flash_map(&mut octopus_map).len() as i32;
         if i + 1 >= N_STEPS_FIRST_PART {
             break;
         }
     }
-    n_flashes
+    Ok(n_flashes)
 }
 
-pub fn second_part(input: &str) -> i32 {
-    let mut octopus_map = parse(input);
+pub fn second_part(input: &str) -> Result<i32, SolveError> {
+    let mut octopus_map = parse(input)?;
 
     for (i, _) in std::iter::repeat(()).enumerate() {
-        if compute_flash_map(&mut octopus_map)
-            .iter()
-            .all(|(_, &val)| val)
-        {
-            return i as i32 + 1;
+        if compute_flash_map(&mut octopus_map).len() == octopus_map.len() {
+            return Ok(i as i32 + 1);
         }
     }
     unreachable!()
 }
 
-fn compute_flash_map(octopus_map: &mut Grid<i32>) -> Grid<bool> {
-    let mut flash_map = Grid::full(octopus_map.width(), octopus_map.height(), false);
-
+fn compute_flash_map(octopus_map: &mut Grid<i32>) -> HashSet<Point> {
     octopus_map.iter_mut().for_each(|(_, val)| *val += 1);
 
-    let mut to_check_stack: VecDeque<Point> = VecDeque::default();
-
-    for (p, val) in octopus_map.iter() {
-        if *val > 9 {
-            to_check_stack.push_back(p);
-        }
-    }
-
-    while let Some(to_check) = to_check_stack.pop_front() {
-        if *flash_map.at(&to_check).unwrap() {
-            continue;
-        }
-        *flash_map.at_mut(&to_check).unwrap() = true;
-        *octopus_map.at_mut(&to_check).unwrap() = 0;
+    let to_flash = octopus_map
+        .iter()
+        .filter(|(_, val)| **val > 9)
+        .map(|(p, _)| p)
+        .collect::<Vec<_>>();
 
+    // every octopus flashes once, charging those adjacent that have not
+    // reached their flashing level yet
+    let flashed = flood_fill(to_flash, |flashing| {
         let adjacent_points = octopus_map
-            .eight_neighborhood_at(&to_check)
+            .eight_neighborhood_at(flashing)
             .iter()
+            .filter(|(_, val)| **val <= 9)
             .map(|(p, _)| p)
             .cloned()
             .collect::<Vec<_>>();
-        for adj in adjacent_points {
-            if !(*flash_map.at(&adj).unwrap()) {
-                *octopus_map.at_mut(&adj).unwrap() += 1;
-
-                if *octopus_map.at(&adj).unwrap() > 9 {
-                    to_check_stack.push_back(adj);
-                }
-            }
+        for adj in adjacent_points.iter() {
+            *octopus_map.at_mut(adj).unwrap() += 1;
         }
+        adjacent_points
+            .into_iter()
+            .filter(|adj| *octopus_map.at(adj).unwrap() > 9)
+            .collect::<Vec<_>>()
+    });
+
+    for p in flashed.iter() {
+        *octopus_map.at_mut(p).unwrap() = 0;
     }
-
-    flash_map
+    flashed
 }
 
-fn parse(input: &str) -> Grid<i32> {
-    Grid::from_rows(input.lines().map(|line| {
-        line.chars()
-            .map(|c| c.to_string().parse::<i32>().unwrap())
-            .collect()
-    }))
-    .unwrap()
+fn parse(input: &str) -> Result<Grid<i32>, SolveError> {
+    Grid::parse_digits(input)
 }
 
 #[cfg(test)]
 mod tests_day_11 {
     use super::*;
+    use aoc_commons::inputs::real;
 
     const EXAMPLE_INPUT: &str = "5483143223
 2745854711
@@ -101,7 +89,7 @@ mod tests_day_11 {
 
     #[test]
     fn test_parse() {
-        let g = parse(EXAMPLE_INPUT);
+        let g = parse(EXAMPLE_INPUT).unwrap();
 
         assert_eq!(g.width(), 10);
         assert_eq!(g.height(), 10);
@@ -111,20 +99,20 @@ mod tests_day_11 {
 
     #[test]
     fn test_example_first_part() {
-        assert_eq!(first_part(EXAMPLE_INPUT), 1656);
+        assert_eq!(first_part(EXAMPLE_INPUT), Ok(1656));
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/11.in")), 1627);
+        assert_eq!(first_part(&real(2021, 11)), Ok(1627));
     }
 
     #[test]
     fn test_example_second_part() {
-        assert_eq!(second_part(EXAMPLE_INPUT), 195);
+        assert_eq!(second_part(EXAMPLE_INPUT), Ok(195));
     }
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/11.in")), -1);
+        assert_eq!(second_part(&real(2021, 11)), Ok(-1));
     }
 }
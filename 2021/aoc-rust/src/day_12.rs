@@ -1,11 +1,8 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
 
-use crate::graph::{Graph, SparseGraph};
+use aoc_commons::graph::{Graph, PathVisitor, SparseGraph};
 
-struct PathState {
-    vertex: String,
-    visited_counts: HashMap<String, i32>,
-}
+aoc_commons::solution!(2021, 12, first_part, second_part);
 
 pub fn first_part(input: &str) -> i32 {
     count_paths(&parse(input), 0)
@@ -16,66 +13,58 @@ pub fn second_part(input: &str) -> i32 {
 }
 
 fn count_paths(graph: &SparseGraph<String>, max_double_visits: i32) -> i32 {
-    let mut dfs_stack: VecDeque<PathState> = VecDeque::new();
-
-    dfs_stack.push_front(PathState {
-        vertex: "start".to_string(),
-        visited_counts: vec![("start".to_string(), 1)].into_iter().collect(),
-    });
-
-    let mut end_counter = 0;
-
-    while !dfs_stack.is_empty() {
-        let current = dfs_stack.pop_front().unwrap();
-
-        for adjacent in graph.iter_adjacents(&current.vertex).unwrap() {
-            if *adjacent == "end".to_string() {
-                end_counter += 1;
-            } else if is_reachable(&current, adjacent, max_double_visits) {
-                let mut adjacent_visited_counts = current.visited_counts.clone();
-                let adjcent_count_ref =
-                    adjacent_visited_counts.entry(adjacent.clone()).or_insert(0);
-                *adjcent_count_ref += 1;
-                dfs_stack.push_front(PathState {
-                    vertex: adjacent.clone(),
-                    visited_counts: adjacent_visited_counts,
-                });
-            }
-        }
-    }
+    let (start, end) = ("start".to_string(), "end".to_string());
+    let mut visits = SmallCaveVisits {
+        visits: graph
+            .iter_vertices()
+            .filter(|v| is_small_cave(v))
+            .map(|v| (v.clone(), 0))
+            .collect(),
+        double_visits: 0,
+        max_double_visits,
+    };
 
-    end_counter
+    graph.count_paths(&start, &end, &mut visits) as i32
 }
 
 fn is_small_cave(vertex_key: &String) -> bool {
     vertex_key.chars().any(|c| c.is_lowercase())
 }
 
-fn is_reachable(current: &PathState, adjacent: &String, max_double_visits: i32) -> bool {
-    if adjacent == "start" {
-        return false;
-    }
+/// How many times the current path went through each small cave; at most
+/// `max_double_visits` of them may be visited twice.
+struct SmallCaveVisits {
+    visits: HashMap<String, i32>,
+    double_visits: i32,
+    max_double_visits: i32,
+}
 
-    if !is_small_cave(adjacent) {
-        return true;
+impl PathVisitor<String> for SmallCaveVisits {
+    fn can_enter(&self, vertex_key: &String) -> bool {
+        match self.visits.get(vertex_key) {
+            _ if vertex_key == "start" => false,
+            None | Some(0) => true,
+            Some(_) => self.double_visits < self.max_double_visits,
+        }
     }
 
-    let double_visit_count = current
-        .visited_counts
-        .iter()
-        .filter(|(k, &v)| is_small_cave(k) && v > 1)
-        .count() as i32;
-
-    if double_visit_count < max_double_visits {
-        return true;
+    fn enter(&mut self, vertex_key: &String) {
+        if let Some(count) = self.visits.get_mut(vertex_key) {
+            *count += 1;
+            if *count == 2 {
+                self.double_visits += 1;
+            }
+        }
     }
 
-    let adjacent_visited_count = match current.visited_counts.get(adjacent) {
-        Some(val) => *val,
-        None => 0,
-    };
-
-    adjacent_visited_count < 1
+    fn leave(&mut self, vertex_key: &String) {
+        if let Some(count) = self.visits.get_mut(vertex_key) {
+            if *count == 2 {
+                self.double_visits -= 1;
+            }
+            *count -= 1;
+        }
+    }
 }
 
 fn parse(input: &str) -> SparseGraph<String> {
@@ -87,14 +76,15 @@ fn parse(input: &str) -> SparseGraph<String> {
 
 #[cfg(test)]
 mod tests_day_12 {
+    use aoc_commons::inputs::{custom, example, real};
     use std::collections::HashSet;
 
     use super::{first_part, parse, second_part};
-    use crate::graph::{Graph, Sizable, SparseGraph};
+    use aoc_commons::graph::{Graph, Sizable};
 
     #[test]
     fn test_parsing() {
-        let g = parse(include_str!("../inputs/12_example"));
+        let g = parse(&example(2021, 12, 1));
 
         assert_eq!(g.len(), 6);
         assert_eq!(
@@ -107,29 +97,23 @@ mod tests_day_12 {
 
     #[test]
     fn test_example_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/12_example")), 10);
-        assert_eq!(
-            first_part(include_str!("../inputs/12_even_larger_example")),
-            226
-        );
+        assert_eq!(first_part(&example(2021, 12, 1)), 10);
+        assert_eq!(first_part(&custom(2021, 12, "even_larger_example")), 226);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/12.in")), 5958);
+        assert_eq!(first_part(&real(2021, 12)), 5958);
     }
 
     #[test]
     fn test_example_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/12_example")), 36);
-        assert_eq!(
-            second_part(include_str!("../inputs/12_even_larger_example")),
-            3509
-        );
+        assert_eq!(second_part(&example(2021, 12, 1)), 36);
+        assert_eq!(second_part(&custom(2021, 12, "even_larger_example")), 3509);
     }
 
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/12.in")), 150426);
+        assert_eq!(second_part(&real(2021, 12)), 150426);
     }
 }
@@ -1,62 +1,97 @@
-use rustc_hash::FxHashSet;
+use aoc_commons::grid::{Grid, Point};
+use aoc_commons::p;
 
-use crate::grid::{Grid, Point};
+aoc_commons::solution!(2021, 13, first_part, second_part);
 
 pub fn first_part(input: &str) -> i32 {
     let (points, folds) = parse(input);
-    points
-        .iter()
-        .map(|p| fold_point(p, &folds[0..1]))
-        .collect::<FxHashSet<Point>>()
-        .len() as i32
+    let mut paper = build_paper(&points, &folds);
+    if let Some(fold) = folds.first() {
+        paper = fold_paper(&paper, fold);
+    }
+    paper.iter_values().filter(|&&dot| dot).count() as i32
 }
 
 pub fn second_part(input: &str) -> String {
     let (points, folds) = parse(input);
 
-    let paper_points = points
+    let paper = folds
         .iter()
-        .map(|p| fold_point(p, &folds))
-        .collect::<FxHashSet<_>>();
+        .fold(build_paper(&points, &folds), |paper, fold| {
+            fold_paper(&paper, fold)
+        });
 
-    let max_x = paper_points.iter().map(|p| p.x).max().unwrap() + 1;
-    let max_y = paper_points.iter().map(|p| p.y).max().unwrap() + 1;
+    paper.render(|&dot| if dot { '#' } else { ' ' }).to_string()
+}
 
-    let mut out: String = "".to_string();
-    for y in 0..max_y {
-        for x in 0..max_x {
-            if paper_points.contains(&Point::new(x, y)) {
-                out += "#";
-            } else {
-                out += " ";
-            }
-        }
-        out += "\n";
+type Paper = Grid<bool>;
+
+/// The paper is large enough for the first fold along each axis to halve it
+/// exactly, even when no dot lies on its last row or column.
+fn build_paper(points: &[Point], folds: &[Fold]) -> Paper {
+    let size = |axis: FoldAxis, max_dot: Option<i32>| {
+        let first_fold = folds.iter().find(|(a, _)| *a == axis).map(|(_, at)| *at);
+        max_dot
+            .map_or(0, |m| m + 1)
+            .max(first_fold.map_or(0, |at| 2 * at + 1)) as usize
+    };
+    let width = size(FoldAxis::X, points.iter().map(|p| p.x).max());
+    let height = size(FoldAxis::Y, points.iter().map(|p| p.y).max());
+
+    let mut paper = Grid::full(width, height, false);
+    for p in points.iter() {
+        *paper.at_mut(p).unwrap() = true;
     }
-    out
+    paper
 }
 
-fn fold_point(point: &Point, folds: &[Fold]) -> Point {
-    let mut folded_point = *point;
-
-    for fold in folds.iter() {
-        match fold {
-            (FoldAxis::X, amount) => {
-                if folded_point.x > *amount {
-                    folded_point = Point::new(amount - (folded_point.x - amount), folded_point.y);
-                }
-            }
-            (FoldAxis::Y, amount) => {
-                if folded_point.y > *amount {
-                    folded_point = Point::new(folded_point.x, amount - (folded_point.y - amount));
-                }
-            }
+/// Mirrors the part after the fold line onto the part before it. Both halves
+/// are aligned at the fold line, so the larger one decides the folded size.
+fn fold_paper(paper: &Paper, fold: &Fold) -> Paper {
+    let (w, h) = (paper.width(), paper.height());
+    // where the kept half ends and the folded one starts, within the paper
+    let split = |at: i32, size: usize| ((at as usize).min(size), (at as usize + 1).min(size));
+    let (kept, folded) = match fold {
+        (FoldAxis::X, at) => {
+            let (end, start) = split(*at, w);
+            (
+                paper.view(p!(0, 0), end, h).unwrap().to_grid(),
+                paper
+                    .view(p!(start as i32, 0), w - start, h)
+                    .unwrap()
+                    .to_grid()
+                    .flip_horizontal(),
+            )
+        }
+        (FoldAxis::Y, at) => {
+            let (end, start) = split(*at, h);
+            (
+                paper.view(p!(0, 0), w, end).unwrap().to_grid(),
+                paper
+                    .view(p!(0, start as i32), w, h - start)
+                    .unwrap()
+                    .to_grid()
+                    .flip_vertical(),
+            )
+        }
+    };
+
+    let width = kept.width().max(folded.width());
+    let height = kept.height().max(folded.height());
+    let mut folded_paper = Grid::full(width, height, false);
+    for half in [kept, folded] {
+        let offset = p!(
+            (width - half.width()) as i32,
+            (height - half.height()) as i32
+        );
+        for (p, _) in half.iter().filter(|(_, &dot)| dot) {
+            *folded_paper.at_mut(&(p + offset)).unwrap() = true;
         }
     }
-
-    folded_point
+    folded_paper
 }
 
+#[derive(PartialEq, Eq)]
 enum FoldAxis {
     X,
     Y,
@@ -65,7 +100,7 @@ enum FoldAxis {
 type Fold = (FoldAxis, i32);
 
 fn parse(input: &str) -> (Vec<Point>, Vec<Fold>) {
-    let (points, splits) = input.split_once("\n\n").unwrap();
+    let (points, splits) = input.split_once("\n\n").unwrap_or((input, ""));
     (parse_points(points), parse_folds(splits))
 }
 
@@ -96,29 +131,54 @@ fn parse_folds(input: &str) -> Vec<Fold> {
         .collect::<Vec<Fold>>()
 }
 
+#[cfg(test)]
 mod tests_day_13 {
-    use crate::{day_13::first_part, day_13::parse, day_13::second_part};
+    use crate::day_13::{build_paper, first_part, fold_paper, parse, second_part, FoldAxis};
+    use aoc_commons::inputs::{example, real};
 
     #[test]
     fn test_parsing() {
-        let (points, splits) = parse(include_str!("../inputs/13_example"));
+        let (points, splits) = parse(&example(2021, 13, 1));
         assert_eq!(points.len(), 18);
         assert_eq!(splits.len(), 2);
     }
 
+    #[test]
+    fn test_fold_larger_half() {
+        // the folded half reaches two columns beyond the kept one
+        let (points, folds) = parse("0,0\n5,1\n\nfold along x=1\n");
+        let paper = fold_paper(&build_paper(&points, &folds), &folds[0]);
+
+        assert_eq!((paper.width(), paper.height()), (4, 2));
+        assert_eq!(
+            paper.render(|&dot| if dot { '#' } else { '.' }).to_string(),
+            "...#\n#...\n"
+        );
+
+        let beyond_edge = fold_paper(&paper, &(FoldAxis::Y, 5));
+        assert_eq!(beyond_edge, paper);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(first_part(""), 0);
+        assert_eq!(first_part("\n\nfold along y=3\n"), 0);
+        assert_eq!(second_part(""), "");
+    }
+
     #[test]
     fn test_first_part_on_example() {
-        assert_eq!(first_part(include_str!("../inputs/13_example")), 17);
+        assert_eq!(first_part(&example(2021, 13, 1)), 17);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/13.in")), 775);
+        assert_eq!(first_part(&real(2021, 13)), 775);
     }
 
     #[test]
     fn test_second_part() {
-        let out = second_part(include_str!("../inputs/13.in"));
+        let out = second_part(&real(2021, 13));
         println!("{}", out);
 
         assert_eq!(out.lines().count(), 6);
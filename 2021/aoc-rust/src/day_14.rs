@@ -1,5 +1,9 @@
 use std::{char, collections::HashMap};
 
+use aoc_commons::memo::Memo;
+
+aoc_commons::solution!(2021, 14, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     solve(input, 10)
 }
@@ -12,16 +16,10 @@ fn solve(input: &str, n_iterations: i32) -> i64 {
     let (polymer, rules) = parse(input);
 
     let mut element_counts = count_elements(&polymer);
-    let mut memoization_table = HashMap::new();
+    let mut memo = Memo::new();
 
     for window in polymer.chars().collect::<Vec<_>>().windows(2) {
-        let counts = count_polymers(
-            window[0],
-            window[1],
-            n_iterations,
-            &rules,
-            &mut memoization_table,
-        );
+        let counts = count_polymers(window[0], window[1], n_iterations, &rules, &mut memo);
 
         element_counts = merge_counts(&element_counts, &counts);
     }
@@ -48,28 +46,23 @@ fn count_polymers(
     right: char,
     ttl: i32,
     rules: &HashMap<(char, char), char>,
-    memoization_table: &mut HashMap<(char, char, i32), Counter>,
+    memo: &mut Memo<(char, char, i32), Counter>,
 ) -> Counter {
-    if let Some(val) = memoization_table.get(&(left, right, ttl)) {
-        return val.to_owned();
-    }
-
-    if ttl == 0 {
-        Counter::new()
-    } else {
+    memo.get_or_compute((left, right, ttl), |memo, _| {
+        if ttl == 0 {
+            return Counter::new();
+        }
         let middle = rules.get(&(left, right)).unwrap();
 
         let mut merged = merge_counts(
-            &count_polymers(left, *middle, ttl - 1, rules, memoization_table),
-            &count_polymers(*middle, right, ttl - 1, rules, memoization_table),
+            &count_polymers(left, *middle, ttl - 1, rules, memo),
+            &count_polymers(*middle, right, ttl - 1, rules, memo),
         );
 
         let middle_val = merged.entry(*middle).or_insert(0);
         *middle_val += 1;
-
-        memoization_table.insert((left, right, ttl), merged.clone());
         merged
-    }
+    })
 }
 
 fn merge_counts(a: &Counter, b: &Counter) -> Counter {
@@ -103,12 +96,14 @@ fn parse_rules(input: &str) -> HashMap<(char, char), char> {
         .collect::<HashMap<(char, char), char>>()
 }
 
+#[cfg(test)]
 mod tests_day_14 {
     use crate::{day_14::first_part, day_14::parse, day_14::second_part};
+    use aoc_commons::inputs::{example, real};
 
     #[test]
     fn test_parse() {
-        let (polymer, rules) = parse(include_str!("../inputs/14_example"));
+        let (polymer, rules) = parse(&example(2021, 14, 1));
 
         assert_eq!(polymer, "NNCB".to_string());
         assert_eq!(rules.len(), 16);
@@ -117,24 +112,21 @@ mod tests_day_14 {
 
     #[test]
     fn test_example_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/14_example")), 1588);
+        assert_eq!(first_part(&example(2021, 14, 1)), 1588);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/14.in")), 2851);
+        assert_eq!(first_part(&real(2021, 14)), 2851);
     }
 
     #[test]
     fn test_example_second_part() {
-        assert_eq!(
-            second_part(include_str!("../inputs/14_example")),
-            2188189693529
-        );
+        assert_eq!(second_part(&example(2021, 14, 1)), 2188189693529);
     }
 
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/14.in")), 10002813279337);
+        assert_eq!(second_part(&real(2021, 14)), 10002813279337);
     }
 }
@@ -1,3 +1,5 @@
+aoc_commons::solution!(2021, 16, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     let (packet, _) = interpret_packet(&parse(input));
     sum_versions(&packet)
@@ -242,6 +244,7 @@ fn array_to_num(sequence: &[bool]) -> i64 {
 #[cfg(test)]
 mod tests_day_16 {
     use crate::day_16::{array_to_num, first_part, num_to_bool_array, parse, second_part};
+    use aoc_commons::inputs::real;
 
     #[test]
     fn test_parse() {
@@ -273,7 +276,7 @@ mod tests_day_16 {
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/16.in")), 854);
+        assert_eq!(first_part(&real(2021, 16)), 854);
     }
 
     #[test]
@@ -289,6 +292,6 @@ mod tests_day_16 {
     }
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/16.in")), 186189840660);
+        assert_eq!(second_part(&real(2021, 16)), 186189840660);
     }
 }
@@ -3,10 +3,12 @@ use std::i32;
 use itertools::Itertools;
 use regex::Regex;
 
-use crate::{grid::Point, p};
+use aoc_commons::{grid::Point, p};
 
 const r#const: i32 = 200;
 
+aoc_commons::solution!(2021, 17, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     let target = parse(input);
     generate_possibilities(target)
@@ -93,10 +95,8 @@ fn parse(r#str: &str) -> (Point, Point) {
 
 #[cfg(test)]
 mod tests_day_17 {
-    use crate::{
-        day_17::{first_part, r#match, parse, second_part},
-        grid::Point, p,
-    };
+    use aoc_commons::{grid::Point, inputs::real, p};
+    use crate::day_17::{first_part, r#match, parse, second_part};
 
     #[test]
     fn test_parse() {
@@ -128,7 +128,7 @@ mod tests_day_17 {
     }
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/17.in")), 8646);
+        assert_eq!(first_part(&real(2021, 17)), 8646);
     }
     #[test]
     fn test_example_second_part() {
@@ -136,6 +136,6 @@ mod tests_day_17 {
     }
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/17.in")), 5945);
+        assert_eq!(second_part(&real(2021, 17)), 5945);
     }
 }
@@ -1,15 +1,14 @@
-use core::{fmt, panic};
-use std::{char, fmt::Debug, iter::Peekable, rc::Rc};
+use std::fmt;
+use std::str::FromStr;
 
 use itertools::Itertools;
 
+aoc_commons::solution!(2021, 18, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     let numbers = parse(input);
 
-    let result = numbers
-        .into_iter()
-        .reduce(|a, b| add(a.clone(), b.clone()))
-        .unwrap();
+    let result = numbers.into_iter().reduce(|a, b| a.add(&b)).unwrap();
 
     result.magnitude()
 }
@@ -19,353 +18,175 @@ pub fn second_part(input: &str) -> i64 {
 
     numbers
         .iter()
-        .cloned()
-        .cartesian_product(numbers.iter().cloned())
+        .cartesian_product(numbers.iter())
         .filter(|(a, b)| a != b)
-        .map(|(a, b)| add(a, b).magnitude())
-        .max().unwrap()
+        .map(|(a, b)| a.add(b).magnitude())
+        .max()
+        .unwrap()
+}
+
+/// Regular number together with the count of pairs it is nested in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Literal {
+    depth: u32,
+    value: u32,
 }
 
+/// Snailfish number flattened to its regular numbers from left to right;
+/// the pairs are implied by the depths.
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct SnailFishNumber {
-    lhs: Rc<SnailFishNode>,
-    rhs: Rc<SnailFishNode>,
-}
-#[derive(PartialEq, Eq, Clone)]
-enum SnailFishNode {
-    Literal(i32),
-    Nested(SnailFishNumber),
+    literals: Vec<Literal>,
 }
 
-impl SnailFishNode {
-    fn magnitude(&self) -> i64 {
-        match self {
-            SnailFishNode::Literal(lit) => *lit as i64,
-            SnailFishNode::Nested(nested) => {
-                3 * nested.lhs.magnitude() + 2 * nested.rhs.magnitude()
-            }
-        }
-    }
-}
+impl SnailFishNumber {
+    fn add(&self, other: &SnailFishNumber) -> SnailFishNumber {
+        let literals = self
+            .literals
+            .iter()
+            .chain(&other.literals)
+            .map(|l| Literal {
+                depth: l.depth + 1,
+                value: l.value,
+            })
+            .collect();
 
-impl fmt::Debug for SnailFishNode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            SnailFishNode::Literal(lit) => {
-                write!(f, "{}", lit)?;
-            }
-            SnailFishNode::Nested(nested) => {
-                write!(f, "[")?;
-                nested.lhs.as_ref().fmt(f)?;
-                write!(f, ",")?;
-                nested.rhs.as_ref().fmt(f)?;
-                write!(f, "]")?;
-            }
-        };
-        Ok(())
+        let mut sum = SnailFishNumber { literals };
+        sum.reduce();
+        sum
     }
-}
 
-fn add(a: Rc<SnailFishNode>, b: Rc<SnailFishNode>) -> Rc<SnailFishNode> {
-    reduce(Rc::new(SnailFishNode::Nested(SnailFishNumber {
-        lhs: a,
-        rhs: b,
-    })))
-}
-
-fn reduce(n: Rc<SnailFishNode>) -> Rc<SnailFishNode> {
-    let mut new_n = n.clone();
-    loop {
-        let (x, n_operations) = explode(new_n.clone());
-
-        if n_operations > 0 {
-            new_n = x.clone();
-            continue;
-        }
-
-        let (x, n_operations) = split(new_n.clone(), 0);
-
-        if n_operations > 0 {
-            new_n = x.clone();
-            continue;
-        }
-
-        break;
+    fn reduce(&mut self) {
+        while self.explode() || self.split() {}
     }
 
-    new_n
-}
-
-fn explode(n: Rc<SnailFishNode>) -> (Rc<SnailFishNode>, i32) {
-    let mut new_n = n.clone();
+    /// Explodes the leftmost pair nested inside four pairs, if there is one.
+    fn explode(&mut self) -> bool {
+        let Some(i) = self.literals.iter().position(|l| l.depth > 4) else {
+            return false;
+        };
+        let (lhs, rhs) = (self.literals[i], self.literals[i + 1]);
 
-    let mut to_explode = {
-        match find_first_explode_node(new_n.clone(), 0) {
-            Some(_n) => _n,
-            None => return (n, 0),
+        if i > 0 {
+            self.literals[i - 1].value += lhs.value;
         }
-    };
-
-    let lhs: i32 = {
-        match to_explode.as_ref() {
-            SnailFishNode::Literal(_) => panic!("Exploded node is not supposed to be literal"),
-            SnailFishNode::Nested(nested) => match nested.lhs.as_ref() {
-                SnailFishNode::Literal(lit) => *lit,
-                SnailFishNode::Nested(_) => {
-                    panic!("Lhs of exploded node is supposed to be literal.")
-                }
-            },
+        if let Some(next) = self.literals.get_mut(i + 2) {
+            next.value += rhs.value;
         }
-    };
-    let rhs: i32 = {
-        match to_explode.as_ref() {
-            SnailFishNode::Literal(_) => panic!("Exploded node is not supposed to be literal"),
-            SnailFishNode::Nested(nested) => match nested.rhs.as_ref() {
-                SnailFishNode::Literal(lit) => *lit,
-                SnailFishNode::Nested(_) => {
-                    panic!("Lhs of exploded node is supposed to be literal.")
-                }
-            },
-        }
-    };
-
-    let maybe_prev_lit =
-        find_prev_literal_before_target(new_n.clone(), to_explode.clone(), false).0;
-
-    if let Some(prev_lit) = maybe_prev_lit {
-        let new_prev = {
-            match prev_lit.as_ref() {
-                SnailFishNode::Literal(lit) => lit,
-                SnailFishNode::Nested(_) => panic!("Found prev should be a lit"),
-            }
+        self.literals[i] = Literal {
+            depth: lhs.depth - 1,
+            value: 0,
         };
-        new_n = modify(
-            new_n.clone(),
-            prev_lit.clone(),
-            Rc::new(SnailFishNode::Literal(new_prev + lhs)),
-        );
+        self.literals.remove(i + 1);
+        true
     }
 
-    to_explode = find_first_explode_node(new_n.clone(), 0).unwrap();
+    /// Splits the leftmost regular number of at least 10, if there is one.
+    fn split(&mut self) -> bool {
+        let Some(i) = self.literals.iter().position(|l| l.value >= 10) else {
+            return false;
+        };
+        let Literal { depth, value } = self.literals[i];
 
-    let maybe_next_lit = find_next_literal_after_target(new_n.clone(), to_explode.clone(), false).0;
-    if let Some(next_lit) = maybe_next_lit {
-        let new_next = {
-            match next_lit.as_ref() {
-                SnailFishNode::Literal(lit) => lit,
-                SnailFishNode::Nested(_) => panic!("Found next should be a lit"),
-            }
+        self.literals[i] = Literal {
+            depth: depth + 1,
+            value: value / 2,
         };
-        new_n = modify(
-            new_n.clone(),
-            next_lit.clone(),
-            Rc::new(SnailFishNode::Literal(new_next + rhs)),
+        self.literals.insert(
+            i + 1,
+            Literal {
+                depth: depth + 1,
+                value: value.div_ceil(2),
+            },
         );
+        true
     }
 
-    to_explode = find_first_explode_node(new_n.clone(), 0).unwrap();
-
-    new_n = modify(
-        new_n.clone(),
-        to_explode.clone(),
-        Rc::new(SnailFishNode::Literal(0)),
-    );
-
-    (new_n, 1)
-}
+    fn magnitude(&self) -> i64 {
+        pair_magnitude(&self.literals).expect("literals do not form pairs")
+    }
 
-fn split(n: Rc<SnailFishNode>, n_splits: i32) -> (Rc<SnailFishNode>, i32) {
-    match n.as_ref() {
-        SnailFishNode::Literal(literal) => {
-            if literal >= &10 && n_splits == 0 {
-                let lhs = literal / 2;
-                let rhs = if literal % 2 == 0 { lhs } else { lhs + 1 };
-                (
-                    Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                        lhs: Rc::new(SnailFishNode::Literal(lhs)),
-                        rhs: Rc::new(SnailFishNode::Literal(rhs)),
-                    })),
-                    1,
-                )
-            } else {
-                (n, n_splits)
-            }
-        }
-        SnailFishNode::Nested(nested) => {
-            let (lhs, n_lhs) = split(nested.lhs.clone(), n_splits);
-            let (rhs, n_both) = split(nested.rhs.clone(), n_lhs);
-            (
-                Rc::new(SnailFishNode::Nested(SnailFishNumber { lhs, rhs })),
-                n_both,
-            )
+    fn write_node(&self, f: &mut fmt::Formatter<'_>, index: &mut usize, depth: u32) -> fmt::Result {
+        let literal = self.literals[*index];
+        if literal.depth == depth {
+            *index += 1;
+            return write!(f, "{}", literal.value);
         }
+        write!(f, "[")?;
+        self.write_node(f, index, depth + 1)?;
+        write!(f, ",")?;
+        self.write_node(f, index, depth + 1)?;
+        write!(f, "]")
     }
 }
 
-fn find_first_explode_node(n: Rc<SnailFishNode>, nesting_level: i32) -> Option<Rc<SnailFishNode>> {
-    match n.as_ref() {
-        SnailFishNode::Literal(_) => None,
-        SnailFishNode::Nested(nested) => {
-            if nesting_level >= 3 && matches!(*nested.lhs, SnailFishNode::Nested(_)) {
-                return Some(nested.lhs.clone());
-            }
-            if nesting_level >= 3 && matches!(*nested.rhs, SnailFishNode::Nested(_)) {
-                return Some(nested.rhs.clone());
-            }
-            if let Some(lhs) = find_first_explode_node(nested.lhs.clone(), nesting_level + 1) {
-                return Some(lhs);
-            } else {
+/// Magnitude of the literals folded back into pairs, `None` if they do not
+/// form a single pair tree. The two halves of a pair are the first adjacent
+/// results of the same depth, since everything in between is nested deeper.
+fn pair_magnitude(literals: &[Literal]) -> Option<i64> {
+    let mut stack: Vec<(u32, i64)> = vec![];
+    for literal in literals {
+        let mut top = (literal.depth, literal.value as i64);
+        while let Some(&(depth, lhs)) = stack.last() {
+            if depth != top.0 || depth == 0 {
+                break;
             }
-            if let Some(rhs) = find_first_explode_node(nested.rhs.clone(), nesting_level + 1) {
-                return Some(rhs);
-            } else {
-            }
-            None
+            stack.pop();
+            top = (depth - 1, 3 * lhs + 2 * top.1);
         }
+        stack.push(top);
     }
-}
 
-fn modify(
-    n: Rc<SnailFishNode>,
-    to_replace: Rc<SnailFishNode>,
-    replace_with: Rc<SnailFishNode>,
-) -> Rc<SnailFishNode> {
-    match n.as_ref() {
-        SnailFishNode::Literal(_) => n,
-        SnailFishNode::Nested(nested) => {
-            if Rc::as_ptr(&nested.rhs) == Rc::as_ptr(&to_replace) {
-                Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                    lhs: nested.lhs.clone(),
-                    rhs: replace_with.clone(),
-                }))
-            } else if Rc::as_ptr(&nested.lhs) == Rc::as_ptr(&to_replace) {
-                Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                    lhs: replace_with.clone(),
-                    rhs: nested.rhs.clone(),
-                }))
-            } else {
-                Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                    lhs: modify(nested.lhs.clone(), to_replace.clone(), replace_with.clone()),
-                    rhs: modify(nested.rhs.clone(), to_replace.clone(), replace_with.clone()),
-                }))
-            }
-        }
+    match stack[..] {
+        [(0, magnitude)] => Some(magnitude),
+        _ => None,
     }
 }
 
-fn find_next_literal_after_target(
-    n: Rc<SnailFishNode>,
-    target: Rc<SnailFishNode>,
-    target_found: bool,
-) -> (Option<Rc<SnailFishNode>>, bool) {
-    match n.as_ref() {
-        SnailFishNode::Literal(_) => {
-            if target_found {
-                (Some(n.clone()), target_found)
-            } else {
-                (None, target_found)
-            }
-        }
-        SnailFishNode::Nested(nested) => {
-            let is_target = Rc::as_ptr(&n) == Rc::as_ptr(&target);
-
-            if is_target {
-                return (None, true);
-            } else {
-                let (_match, is_target_found) = find_next_literal_after_target(
-                    nested.lhs.clone(),
-                    target.clone(),
-                    target_found,
-                );
-                if let Some(_m) = _match {
-                    return (Some(_m), is_target_found);
-                }
-
-                return find_next_literal_after_target(
-                    nested.rhs.clone(),
-                    target.clone(),
-                    is_target_found,
-                );
-            }
-        }
+impl fmt::Display for SnailFishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_node(f, &mut 0, 0)
     }
 }
-fn find_prev_literal_before_target(
-    n: Rc<SnailFishNode>,
-    target: Rc<SnailFishNode>,
-    target_found: bool,
-) -> (Option<Rc<SnailFishNode>>, bool) {
-    match n.as_ref() {
-        SnailFishNode::Literal(_) => {
-            if target_found {
-                (Some(n.clone()), target_found)
-            } else {
-                (None, target_found)
-            }
-        }
-        SnailFishNode::Nested(nested) => {
-            let is_target = Rc::as_ptr(&n) == Rc::as_ptr(&target);
-
-            if is_target {
-                return (None, true);
-            } else {
-                let (_match, is_target_found) = find_prev_literal_before_target(
-                    nested.rhs.clone(),
-                    target.clone(),
-                    target_found,
-                );
-                if let Some(_m) = _match {
-                    return (Some(_m), is_target_found);
-                }
 
-                return find_prev_literal_before_target(
-                    nested.lhs.clone(),
-                    target.clone(),
-                    is_target_found,
-                );
+impl FromStr for SnailFishNumber {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut literals = vec![];
+        let mut depth: u32 = 0;
+        let mut chars = s.trim().chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '[' => depth += 1,
+                ']' => depth = depth.checked_sub(1).ok_or("unmatched ']'")?,
+                ',' | ' ' => (),
+                _ => {
+                    let mut value = ch
+                        .to_digit(10)
+                        .ok_or(format!("unexpected character {ch:?}"))?;
+                    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                        value = 10 * value + digit;
+                        chars.next();
+                    }
+                    literals.push(Literal { depth, value });
+                }
             }
         }
-    }
-}
-fn parse_snailfish_numbfer(input: &str) -> Rc<SnailFishNode> {
-    let mut stack: Vec<SnailFishNode> = vec![];
-
-    let mut input_iter = input.chars().peekable();
-
-    while let Some(ch) = input_iter.peek() {
-        if ch.is_digit(10) {
-            stack.push(SnailFishNode::Literal(parse_i32(&mut input_iter)));
-        } else if *ch == ']' {
-            input_iter.next();
-            let rhs = stack.pop().expect("rhs missing on stack");
-            let lhs = stack.pop().expect("lhs missing on stack");
-            let new_num = SnailFishNumber {
-                lhs: Rc::new(lhs),
-                rhs: Rc::new(rhs),
-            };
-            stack.push(SnailFishNode::Nested(new_num));
-        } else {
-            input_iter.next();
-        }
-    }
 
-    Rc::new(stack.pop().expect("invalid stack at EOF"))
-}
-
-fn parse_i32(input: &mut Peekable<impl Iterator<Item = char>>) -> i32 {
-    let mut stack = "".to_string();
-
-    while input.peek().is_some_and(|ch| ch.is_digit(10)) {
-        stack.push(input.next().unwrap());
+        if depth != 0 || pair_magnitude(&literals).is_none() {
+            return Err(format!("{s:?} is not a snailfish number"));
+        }
+        Ok(SnailFishNumber { literals })
     }
-    stack.parse::<i32>().unwrap()
 }
 
-fn parse(input: &str) -> Vec<Rc<SnailFishNode>> {
+fn parse(input: &str) -> Vec<SnailFishNumber> {
     input
         .lines()
-        .filter(|line| line.contains("["))
-        .map(|line| parse_snailfish_numbfer(line))
+        .filter(|line| line.contains('['))
+        .map(|line| line.parse().unwrap())
         .collect()
 }
 
@@ -373,6 +194,18 @@ fn parse(input: &str) -> Vec<Rc<SnailFishNode>> {
 mod tests_day_18 {
 
     use super::*;
+    use aoc_commons::inputs::{example, real};
+
+    fn sf(text: &str) -> SnailFishNumber {
+        text.parse().unwrap()
+    }
+
+    fn literals(depths_and_values: &[(u32, u32)]) -> Vec<Literal> {
+        depths_and_values
+            .iter()
+            .map(|&(depth, value)| Literal { depth, value })
+            .collect()
+    }
 
     #[test]
     fn test_parsing() {
@@ -393,153 +226,93 @@ mod tests_day_18 {
     }
 
     #[test]
-    fn test_snailfish() {
-        assert_eq!(SnailFishNode::Literal(3), SnailFishNode::Literal(3));
+    fn test_parse_sf() {
+        assert_eq!(sf("[1, 2]").literals, literals(&[(1, 1), (1, 2)]));
         assert_eq!(
-            SnailFishNode::Nested(SnailFishNumber {
-                lhs: Rc::new(SnailFishNode::Literal(1)),
-                rhs: Rc::new(SnailFishNode::Literal(2)),
-            }),
-            SnailFishNode::Nested(SnailFishNumber {
-                lhs: Rc::new(SnailFishNode::Literal(1)),
-                rhs: Rc::new(SnailFishNode::Literal(2)),
-            })
+            sf("[9,[8,7]]").literals,
+            literals(&[(1, 9), (2, 8), (2, 7)])
         );
+
+        assert!("[1,2".parse::<SnailFishNumber>().is_err());
+        assert!("[1,2,3]".parse::<SnailFishNumber>().is_err());
+        assert!("[[1,2]]".parse::<SnailFishNumber>().is_err());
+        assert!("[1,x]".parse::<SnailFishNumber>().is_err());
     }
 
     #[test]
-    fn test_parse_sf() {
-        assert_eq!(
-            *parse_snailfish_numbfer("[1, 2]"),
-            SnailFishNode::Nested(SnailFishNumber {
-                lhs: Rc::new(SnailFishNode::Literal(1)),
-                rhs: Rc::new(SnailFishNode::Literal(2))
-            })
-        );
-        assert_eq!(
-            *parse_snailfish_numbfer("[9,[8,7]]"),
-            SnailFishNode::Nested(SnailFishNumber {
-                lhs: Rc::new(SnailFishNode::Literal(9)),
-                rhs: Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                    lhs: Rc::new(SnailFishNode::Literal(8)),
-                    rhs: Rc::new(SnailFishNode::Literal(7)),
-                })),
-            })
-        );
+    fn test_display() {
+        for text in [
+            "[1,2]",
+            "[9,[8,7]]",
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+        ] {
+            assert_eq!(sf(text).to_string(), text);
+        }
+        assert_eq!(sf("[ 12, [3,4] ]").to_string(), "[12,[3,4]]");
     }
 
     #[test]
     fn test_split() {
-        assert_eq!(
-            split(parse_snailfish_numbfer("[11,[18,7]]"), 0),
-            (
-                Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                    lhs: Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                        lhs: Rc::new(SnailFishNode::Literal(5)),
-                        rhs: Rc::new(SnailFishNode::Literal(6)),
-                    })),
-                    rhs: Rc::new(SnailFishNode::Nested(SnailFishNumber {
-                        lhs: Rc::new(SnailFishNode::Literal(18)),
-                        rhs: Rc::new(SnailFishNode::Literal(7)),
-                    })),
-                })),
-                1
-            )
-        );
-    }
+        let mut n = sf("[11,[18,7]]");
 
-    #[test]
-    fn test_find_first_explode() {
-        assert_eq!(
-            *find_first_explode_node(parse_snailfish_numbfer("[[[[[9,8],1],2],3],4]"), 0).unwrap(),
-            SnailFishNode::Nested(SnailFishNumber {
-                lhs: Rc::new(SnailFishNode::Literal(9)),
-                rhs: Rc::new(SnailFishNode::Literal(8))
-            })
-        );
-        assert_eq!(
-            find_first_explode_node(parse_snailfish_numbfer("[[[[3,1],2],3],4]"), 0),
-            None
-        )
+        assert!(n.split());
+        assert_eq!(n, sf("[[5,6],[18,7]]"));
+        assert!(n.split());
+        assert_eq!(n, sf("[[5,6],[[9,9],7]]"));
+        assert!(!n.split());
     }
+
     #[test]
-    fn test_modify() {
-        let a = parse_snailfish_numbfer("[1, [2, 3]]");
-        let b = parse_snailfish_numbfer("[[4, 5], 6]");
-
-        if let (SnailFishNode::Nested(nested_a), SnailFishNode::Nested(nested_b)) =
-            (a.as_ref(), b.as_ref())
-        {
-            let to_replace = nested_a.rhs.clone();
-            let replace_with = nested_b.lhs.clone();
-            assert_eq!(
-                modify(a, to_replace, replace_with),
-                parse_snailfish_numbfer("[1,[4,5]]")
-            )
-        } else {
-            assert!(false);
+    fn test_explode() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+        ] {
+            let mut n = sf(before);
+            assert!(n.explode());
+            assert_eq!(n, sf(after));
         }
+        assert!(!sf("[[[[3,1],2],3],4]").explode());
     }
 
     #[test]
-    fn test_find_after_target() {
-        let sn = parse_snailfish_numbfer("[[[2,[1,[9,8]]], 5], 6]");
-        let en = find_first_explode_node(sn.clone(), 0).unwrap();
-
-        assert_eq!(
-            find_next_literal_after_target(sn.clone(), en.clone(), false).0,
-            Some(Rc::new(SnailFishNode::Literal(5)))
-        );
+    fn test_addition() {
+        let a = sf("[[[[4,3],4],4],[7,[[8,4],9]]]");
+        let b = sf("[1,1]");
 
-        assert_eq!(
-            find_prev_literal_before_target(sn.clone(), en.clone(), false).0,
-            Some(Rc::new(SnailFishNode::Literal(1)))
-        );
+        assert_eq!(a.add(&b), sf("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
     }
 
     #[test]
-    fn test_explode() {
-        assert_eq!(
-            explode(parse_snailfish_numbfer("[[[[[9,8],1],2],3],4]")).0,
-            parse_snailfish_numbfer("[[[[0,9],2],3],4]")
-        );
-        assert_eq!(
-            explode(parse_snailfish_numbfer("[7,[6,[5,[4,[3,2]]]]]")).0,
-            parse_snailfish_numbfer("[7,[6,[5,[7,0]]]]")
-        );
+    fn test_magnitude() {
+        assert_eq!(sf("[[1,2],[[3,4],5]]").magnitude(), 143);
         assert_eq!(
-            explode(parse_snailfish_numbfer(
-                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]"
-            ))
-            .0,
-            parse_snailfish_numbfer("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]")
+            sf("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(),
+            3488
         );
     }
 
     #[test]
-    fn test_addition() {
-        let a = parse_snailfish_numbfer("[[[[4,3],4],4],[7,[[8,4],9]]]");
-        let b = parse_snailfish_numbfer("[1,1]");
-
-        assert_eq!(
-            add(a, b),
-            parse_snailfish_numbfer("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
-        );
+    fn test_example_first_part() {
+        assert_eq!(first_part(&example(2021, 18, 1)), 4140);
     }
 
     #[test]
-    fn test_example_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/18_example")), 4140);
+    fn test_example_second_part() {
+        assert_eq!(second_part(&example(2021, 18, 1)), 3993);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/18.in")), 3051);
+        assert_eq!(first_part(&real(2021, 18)), 3051);
     }
 
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/18.in")), 4812);
+        assert_eq!(second_part(&real(2021, 18)), 4812);
     }
-
 }
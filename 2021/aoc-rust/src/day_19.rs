@@ -1,102 +1,220 @@
 use itertools::Itertools;
-use rayon::prelude::*;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 
 use crate::space::{Mat3, Vec3};
 
+aoc_commons::solution!(2021, 19, first_part, second_part);
+
+/// Overlapping scanners see at least this many common beacons.
+const MIN_OVERLAP: usize = 12;
+
 pub fn first_part(input: &str) -> i32 {
     let scanners = parse(input);
 
-    let (base, _) = build_ocean(&scanners);
-    base.len() as i32
+    let ocean = build_ocean(&scanners);
+    ocean.beacons.len() as i32
 }
 
 pub fn second_part(input: &str) -> i32 {
     let scanners = parse(input);
 
-    let (_, locations) = build_ocean(&scanners);
+    let ocean = build_ocean(&scanners);
 
-    locations
+    ocean
+        .scanners
         .iter()
-        .cartesian_product(locations.iter())
-        .map(|(&a, &b)| manhattan_distance(a, b))
+        .tuple_combinations()
+        .map(|(a, b)| manhattan_distance(a.position, b.position))
         .max()
         .unwrap()
 }
 
-fn build_ocean(scanners: &Vec<Vec<Vec3>>) -> (FxHashSet<Vec3>, Vec<Vec3>) {
-    let mut base: FxHashSet<Vec3> = scanners[0].iter().cloned().collect();
-
-    let mut remaining_scanners: VecDeque<(usize, Vec<Vec3>)> = VecDeque::new();
-    for i in 1..scanners.len() {
-        remaining_scanners.push_back((i, scanners[i].clone()));
-    }
-
-    let all_rotations: Vec<Mat3> = generate_rotation_matrices().collect();
+/// Beacons of one scanner in its own coordinates, fingerprinted by squared
+/// distances, which do not change however the scanner is placed or rotated.
+struct Scanner {
+    beacons: Vec<Vec3>,
+    /// sorted squared distances from each beacon to the other ones
+    beacon_fingerprints: Vec<Vec<i32>>,
+    /// sorted squared distances between all pairs of beacons
+    fingerprint: Vec<i32>,
+}
 
-    let mut found_scanners = vec![];
+/// Where a scanner is relative to scanner 0 and the rotation turning its
+/// readings into the orientation of scanner 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ScannerPose {
+    position: Vec3,
+    orientation: Mat3,
+}
 
-    while let Some((scanner_id, scanner_data)) = remaining_scanners.pop_front() {
-        let (n, rotation, offset) =
-            find_best_rotation_and_offset(&scanner_data, &base, &all_rotations);
+/// Beacons and scanner poses (indexed as the input), all relative to scanner 0.
+struct Ocean {
+    beacons: FxHashSet<Vec3>,
+    scanners: Vec<ScannerPose>,
+}
 
-        if n < 12 {
-            remaining_scanners.push_back((scanner_id, scanner_data));
-        } else {
-            for beacon in scanner_data.iter() {
-                base.insert((rotation * (*beacon)) + offset);
-            }
-            let location = rotation * Vec3::from(vec![0, 0, 0]) + offset;
-            found_scanners.push(location);
+impl Scanner {
+    fn new(beacons: Vec<Vec3>) -> Scanner {
+        let beacon_fingerprints: Vec<Vec<i32>> = beacons
+            .iter()
+            .map(|&a| {
+                let mut distances = beacons
+                    .iter()
+                    .filter(|&&b| b != a)
+                    .map(|&b| squared_distance(a, b))
+                    .collect::<Vec<_>>();
+                distances.sort_unstable();
+                distances
+            })
+            .collect();
+
+        let mut fingerprint = beacons
+            .iter()
+            .tuple_combinations()
+            .map(|(&a, &b)| squared_distance(a, b))
+            .collect::<Vec<_>>();
+        fingerprint.sort_unstable();
+
+        Scanner {
+            beacons,
+            beacon_fingerprints,
+            fingerprint,
         }
     }
 
-    (base, found_scanners)
+    /// Whether the scanners share enough distances to possibly overlap: every
+    /// pair of the common beacons contributes one.
+    fn may_overlap(&self, other: &Scanner) -> bool {
+        count_shared(&self.fingerprint, &other.fingerprint) >= MIN_OVERLAP * (MIN_OVERLAP - 1) / 2
+    }
 }
 
-fn manhattan_distance(a: Vec3, b: Vec3) -> i32 {
-    (a.x - b.x).abs() + (a.y - b.y).abs() + (a.z - b.z).abs()
-}
+impl ScannerPose {
+    fn origin() -> ScannerPose {
+        ScannerPose {
+            position: vec![0, 0, 0].into(),
+            orientation: Mat3::rotation_x(0),
+        }
+    }
 
-fn find_best_rotation_and_offset<'a>(
-    beacons: &[Vec3],
-    base: &FxHashSet<Vec3>,
-    possible_rotations: &'a [Mat3],
-) -> (usize, &'a Mat3, Vec3) {
-    possible_rotations
-        .par_iter()
-        .map(|rot| {
-            let rotated_beacons = beacons.iter().map(|&b| rot * b).collect::<Vec<_>>();
+    /// Beacon seen by the scanner in the coordinates of scanner 0.
+    fn place(&self, beacon: Vec3) -> Vec3 {
+        &self.orientation * beacon + self.position
+    }
+}
 
-            let (n, offset) = find_best_offset(&rotated_beacons, base);
+/// Places scanners one by one against already placed ones they may overlap,
+/// starting from scanner 0.
+fn build_ocean(scanners: &[Scanner]) -> Ocean {
+    let rotations: Vec<Mat3> = generate_rotation_matrices().collect();
+
+    let mut poses: Vec<Option<ScannerPose>> = vec![None; scanners.len()];
+    let mut placed_beacons: Vec<Vec<Vec3>> = vec![vec![]; scanners.len()];
+    poses[0] = Some(ScannerPose::origin());
+    placed_beacons[0] = scanners[0].beacons.clone();
+
+    let mut queue = VecDeque::from([0]);
+    while let Some(known) = queue.pop_front() {
+        for other in 0..scanners.len() {
+            if poses[other].is_some() || !scanners[known].may_overlap(&scanners[other]) {
+                continue;
+            }
+            let Some(pose) = align(
+                &scanners[other],
+                &scanners[known],
+                &placed_beacons[known],
+                &rotations,
+            ) else {
+                continue;
+            };
+
+            placed_beacons[other] = scanners[other]
+                .beacons
+                .iter()
+                .map(|&b| pose.place(b))
+                .collect();
+            poses[other] = Some(pose);
+            queue.push_back(other);
+        }
+    }
 
-            (n, rot, offset)
-        })
-        .max_by_key(|x| x.0)
-        .unwrap()
+    Ocean {
+        beacons: placed_beacons.into_iter().flatten().collect(),
+        scanners: poses
+            .into_iter()
+            .enumerate()
+            .map(|(i, pose)| pose.unwrap_or_else(|| panic!("scanner {i} could not be placed")))
+            .collect(),
+    }
 }
 
-fn find_best_offset(beacons: &[Vec3], base: &FxHashSet<Vec3>) -> (usize, Vec3) {
-    beacons
+/// Finds the pose of `scanner` overlapping `known`, whose beacons were already
+/// placed at `known_beacons`. Only beacons with enough common distances are
+/// paired up and each pair votes for the offset under every rotation.
+fn align(
+    scanner: &Scanner,
+    known: &Scanner,
+    known_beacons: &[Vec3],
+    rotations: &[Mat3],
+) -> Option<ScannerPose> {
+    let pairs: Vec<(Vec3, Vec3)> = known
+        .beacon_fingerprints
         .iter()
-        .cartesian_product(base.iter())
-        .map(|(&b, &base_b)| {
-            let offset = base_b - b;
+        .enumerate()
+        .cartesian_product(scanner.beacon_fingerprints.iter().enumerate())
+        .filter(|((_, a), (_, b))| count_shared(a, b) >= MIN_OVERLAP - 1)
+        .map(|((i, _), (j, _))| (known_beacons[i], scanner.beacons[j]))
+        .collect();
+    if pairs.len() < MIN_OVERLAP {
+        return None;
+    }
 
-            let n_matches = match_beacons(
-                &beacons.iter().map(|&_b| _b + offset).collect::<Vec<_>>(),
-                base,
-            );
+    let known_set: FxHashSet<Vec3> = known_beacons.iter().cloned().collect();
+    rotations.iter().find_map(|rotation| {
+        let mut offsets: FxHashMap<Vec3, usize> = FxHashMap::default();
+        for &(a, b) in pairs.iter() {
+            *offsets.entry(a - rotation * b).or_insert(0) += 1;
+        }
+        let (&position, _) = offsets.iter().max_by_key(|(_, n)| **n)?;
+
+        let pose = ScannerPose {
+            position,
+            orientation: rotation.clone(),
+        };
+        let n_matches = scanner
+            .beacons
+            .iter()
+            .filter(|&&b| known_set.contains(&pose.place(b)))
+            .count();
+        (n_matches >= MIN_OVERLAP).then_some(pose)
+    })
+}
 
-            (n_matches, offset)
-        })
-        .max_by_key(|x| x.0)
-        .unwrap()
+/// Number of common values of two sorted slices, counting repetitions.
+fn count_shared(a: &[i32], b: &[i32]) -> usize {
+    let (mut i, mut j, mut n) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                n += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    n
+}
+
+fn manhattan_distance(a: Vec3, b: Vec3) -> i32 {
+    (a.x - b.x).abs() + (a.y - b.y).abs() + (a.z - b.z).abs()
 }
 
-fn match_beacons(beacons: &[Vec3], base: &FxHashSet<Vec3>) -> usize {
-    beacons.iter().filter(|b| base.contains(b)).count()
+fn squared_distance(a: Vec3, b: Vec3) -> i32 {
+    let d = a - b;
+    d.x * d.x + d.y * d.y + d.z * d.z
 }
 
 fn generate_rotation_matrices() -> impl Iterator<Item = Mat3> {
@@ -118,11 +236,12 @@ fn generate_rotation_matrices() -> impl Iterator<Item = Mat3> {
         .flatten()
 }
 
-fn parse(input: &str) -> Vec<Vec<Vec3>> {
+fn parse(input: &str) -> Vec<Scanner> {
     input
         .split("--- scanner")
         .map(|part| parse_scanner(part))
         .filter(|scanner| scanner.len() > 0)
+        .map(Scanner::new)
         .collect()
 }
 
@@ -142,40 +261,62 @@ fn parse_scanner(input: &str) -> Vec<Vec3> {
 #[cfg(test)]
 mod tests_day_19 {
 
+    use aoc_commons::inputs::{example, real};
     use super::*;
 
     #[test]
     fn test_parse() {
-        let scanners = parse(include_str!("../inputs/19_example"));
+        let scanners = parse(&example(2021, 19, 1));
 
         assert_eq!(scanners.len(), 5);
-        assert_eq!(scanners[0][0], vec![404, -588, -901].into());
-        assert_eq!(scanners[4][25], vec![30, -46, -14].into());
+        assert_eq!(scanners[0].beacons[0], vec![404, -588, -901].into());
+        assert_eq!(scanners[4].beacons[25], vec![30, -46, -14].into());
+        assert_eq!(scanners[0].fingerprint.len(), 25 * 24 / 2);
+        assert_eq!(scanners[0].beacon_fingerprints[0].len(), 24);
     }
 
     #[test]
-    fn test_match_beacons() {
-        let base: FxHashSet<Vec3> = vec![
-            vec![1, 0, 0].into(),
-            vec![3, 0, 0].into(),
-            vec![10, 0, 10].into(),
-        ]
-        .iter()
-        .cloned()
-        .collect();
+    fn test_count_shared() {
+        assert_eq!(count_shared(&[1, 2, 2, 5, 7], &[2, 2, 3, 7, 8]), 3);
+        assert_eq!(count_shared(&[1, 2], &[]), 0);
+    }
+
+    #[test]
+    fn test_align() {
+        let scanners = parse(&example(2021, 19, 1));
+        let rotations: Vec<Mat3> = generate_rotation_matrices().collect();
 
-        let beacons = vec![
-            vec![0, 0, 0].into(),
-            vec![0, -2, 0].into(),
-            vec![0, -9, 10].into(),
-        ];
+        assert!(scanners[0].may_overlap(&scanners[1]));
+        assert!(!scanners[0].may_overlap(&scanners[2]));
 
-        let rots: Vec<Mat3> = generate_rotation_matrices().collect();
+        let pose = align(&scanners[1], &scanners[0], &scanners[0].beacons, &rotations).unwrap();
+        assert_eq!(pose.position, vec![68, -1246, -43].into());
+        assert_eq!(
+            pose.place(vec![686, 422, 578].into()),
+            vec![-618, -824, -621].into()
+        );
+    }
+
+    #[test]
+    fn test_build_ocean() {
+        let ocean = build_ocean(&parse(&example(2021, 19, 1)));
 
         assert_eq!(
-            find_best_rotation_and_offset(&beacons, &base, &rots),
-            (3, &Mat3::rotation_z(90), vec![1, 0, 0].into())
+            ocean
+                .scanners
+                .iter()
+                .map(|s| s.position)
+                .collect::<Vec<_>>(),
+            vec![
+                vec![0, 0, 0].into(),
+                vec![68, -1246, -43].into(),
+                vec![1105, -1205, 1229].into(),
+                vec![-92, -2380, -20].into(),
+                vec![-20, -1133, 1061].into(),
+            ]
         );
+        assert_eq!(ocean.scanners[0], ScannerPose::origin());
+        assert!(ocean.beacons.contains(&vec![-618, -824, -621].into()));
     }
 
     #[test]
@@ -188,19 +329,19 @@ mod tests_day_19 {
 
     #[test]
     fn test_example_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/19_example")), 79);
+        assert_eq!(first_part(&example(2021, 19, 1)), 79);
     }
     #[test]
     fn test_example_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/19_example")), 3621);
+        assert_eq!(second_part(&example(2021, 19, 1)), 3621);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/19.in")), 491);
+        assert_eq!(first_part(&real(2021, 19)), 491);
     }
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/19.in")), 13374);
+        assert_eq!(second_part(&real(2021, 19)), 13374);
     }
 }
@@ -1,27 +1,33 @@
-use core::{fmt, panic};
+use aoc_commons::grid::{Boundary, Grid};
+use aoc_commons::p;
+use core::panic;
 use rayon::prelude::*;
-use std::{thread, usize};
+
+aoc_commons::solution!(2021, 20, first_part, second_part);
 
 pub fn first_part(input: &str) -> i64 {
     let (data, image) = parse(input);
 
     let enhanced_image = enhance(image, &data, 2);
-    enhanced_image.data.iter().filter(|&&x| x).count() as i64
+    enhanced_image.iter_values().filter(|&&x| x).count() as i64
 }
 
 pub fn second_part(input: &str) -> i64 {
     let (data, image) = parse(input);
 
     let enhanced_image = enhance(image, &data, 50);
-    enhanced_image.data.iter().filter(|&&x| x).count() as i64
+    enhanced_image.iter_values().filter(|&&x| x).count() as i64
 }
 
 fn enhance(image: Image, data: &[bool], n_times: i32) -> Image {
     let mut enhanced_image = image;
     for i in 0..n_times {
         let is_odd = i % 2 == 1;
-        enhanced_image =
-            convolve_par_iter_mut(&enhanced_image, data, is_odd && *data.first().unwrap());
+        let out_of_bounds_value = is_odd && *data.first().unwrap();
+        enhanced_image = convolve(
+            &enhanced_image.with_boundary(Boundary::Fill(out_of_bounds_value)),
+            data,
+        );
     }
     enhanced_image
 }
@@ -34,126 +40,27 @@ fn char_to_bool(c: &char) -> bool {
     }
 }
 
-struct Image {
-    data: Vec<bool>,
-    width: usize,
-    height: usize,
-}
-
-impl Image {
-    fn empty(width: usize, height: usize) -> Self {
-        Self {
-            data: vec![false; width * height],
-            width,
-            height,
-        }
-    }
-
-    fn at(&self, x: i32, y: i32) -> Option<bool> {
-        if x >= 0 && y >= 0 && x < self.width as i32 && y < self.height as i32 {
-            Some(self.data[(y as usize * self.width) + x as usize])
-        } else {
-            None
-        }
-    }
-}
-
-impl From<Vec<Vec<bool>>> for Image {
-    fn from(value: Vec<Vec<bool>>) -> Self {
-        let w = value.first().unwrap().len();
-        let h = value.len();
-        Self {
-            data: value.into_iter().flatten().collect(),
-            width: w,
-            height: h,
-        }
-    }
-}
-impl fmt::Debug for Image {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in (0..self.height) {
-            for x in (0..self.width) {
-                write!(
-                    f,
-                    "{}",
-                    match self.at(x as i32, y as i32) {
-                        Some(true) => "#",
-                        _ => ".",
-                    }
-                )?;
-            }
-            write!(f, "\n");
-        }
-        Ok(())
-    }
-}
+/// Pixels outside of the image take the value of its `Boundary::Fill`.
+type Image = Grid<bool>;
 
-fn convolve(image: &Image, data: &[bool], out_of_bounds_value: bool) -> Image {
-    let mut output_image = Image::empty(image.width + 2, image.height + 2);
+fn convolve(image: &Image, data: &[bool]) -> Image {
+    let (width, height) = (image.width() + 2, image.height() + 2);
+    let mut output_data = vec![false; width * height];
 
-    for y in 0..output_image.height {
-        for x in 0..output_image.width {
-            output_image.data[(y * output_image.width) + x] =
-                data[window_value(image, (x as i32) - 1, (y as i32) - 1, out_of_bounds_value)];
-        }
-    }
-
-    output_image
-}
-
-fn convolve_par_iter_mut(image: &Image, data: &[bool], out_of_bounds_value: bool) -> Image {
-    let mut output_image = Image::empty(image.width + 2, image.height + 2);
-
-    output_image
-        .data
+    output_data
         .par_iter_mut()
         .enumerate()
         .for_each(|(i, output)| {
-            *output = data[window_value(
-                image,
-                (i % output_image.height) as i32 - 1,
-                (i / output_image.height) as i32 - 1,
-                out_of_bounds_value,
-            )];
+            *output = data[window_value(image, (i % width) as i32 - 1, (i / width) as i32 - 1)];
         });
 
-    output_image
-}
-
-fn convolve_par_chunks_mut(image: &Image, data: &[bool], out_of_bounds_value: bool) -> Image {
-    let mut output_image = Image::empty(image.width + 2, image.height + 2);
-    let num_cores = thread::available_parallelism().unwrap();
-    let chunk_size = output_image.data.len() / num_cores;
-    output_image
-        .data
-        .par_chunks_mut(chunk_size)
-        .enumerate()
-        .for_each(|(i, output)| {
-            output.iter_mut().enumerate().for_each(|(j, o)| {
-                *o = data[window_value(
-                    image,
-                    ((i * chunk_size + j) % output_image.height) as i32 - 1,
-                    ((i * chunk_size + j) / output_image.height) as i32 - 1,
-                    out_of_bounds_value,
-                )];
-            })
-        });
-    output_image
+    Grid::from_vec(width, height, output_data).unwrap()
 }
 
-fn window_value(image: &Image, target_x: i32, target_y: i32, out_of_bounds_value: bool) -> usize {
-    let mut index = 0;
-
-    let mut i = 0;
-    for y in (target_y - 1)..(target_y + 2) {
-        for x in (target_x - 1)..(target_x + 2) {
-            if image.at(x, y).unwrap_or(out_of_bounds_value) {
-                index += 1 << (9 - (i + 1));
-            }
-            i += 1;
-        }
-    }
-    index
+fn window_value(image: &Image, target_x: i32, target_y: i32) -> usize {
+    image
+        .iter_window(p!(target_x - 1, target_y - 1), 3, 3)
+        .fold(0, |index, (_, &lit)| (index << 1) | lit as usize)
 }
 
 fn parse(input: &str) -> (Vec<bool>, Image) {
@@ -165,14 +72,17 @@ fn parse(input: &str) -> (Vec<bool>, Image) {
         .lines()
         .skip(2)
         .map(|line| line.chars().map(|c| char_to_bool(&c)).collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+        .collect::<Image>();
 
-    (scanner_data, image.into())
+    (scanner_data, image)
 }
 
 #[cfg(test)]
 mod tests_day_20 {
     use crate::day_20::{convolve, first_part, second_part, window_value, Image};
+    use aoc_commons::grid::{Boundary, Grid};
+    use aoc_commons::inputs::real;
+    use aoc_commons::p;
 
     use super::parse;
 
@@ -183,23 +93,23 @@ mod tests_day_20 {
         let (data, image) = parse(EXAMPLE_INPUT);
 
         assert_eq!(data.len(), 512);
-        assert_eq!(image.width, 5);
-        assert_eq!(image.height, 5);
+        assert_eq!(image.width(), 5);
+        assert_eq!(image.height(), 5);
     }
 
     #[test]
     fn test_convolve() {
-        let mut image = Image::empty(2, 2);
-        image.data[0] = true;
+        let mut image: Image = Grid::full(2, 2, false).with_boundary(Boundary::Fill(false));
+        *image.at_mut(&p!(0, 0)).unwrap() = true;
 
         let data: Vec<bool> = (0..512).map(|i| i == 1).collect();
-        assert_eq!(window_value(&image, 0, 0, false), 16);
-        let convolved_image = convolve(&image, &data, false);
+        assert_eq!(window_value(&image, 0, 0), 16);
+        let convolved_image = convolve(&image, &data);
 
-        assert_eq!(convolved_image.width, 4);
-        assert_eq!(convolved_image.height, 4);
+        assert_eq!(convolved_image.width(), 4);
+        assert_eq!(convolved_image.height(), 4);
         assert_eq!(
-            convolved_image.data,
+            convolved_image.iter_values().cloned().collect::<Vec<_>>(),
             vec![
                 true, false, false, false, false, false, false, false, false, false, false, false,
                 false, false, false, false
@@ -209,8 +119,9 @@ mod tests_day_20 {
 
     #[test]
     fn test_convolve_example() {
-        let (data, image) = parse(EXAMPLE_INPUT);
-        assert_eq!(window_value(&image, 2, 2, false), 34);
+        let (_, image) = parse(EXAMPLE_INPUT);
+        let image = image.with_boundary(Boundary::Fill(false));
+        assert_eq!(window_value(&image, 2, 2), 34);
     }
     #[test]
     fn test_example_first_part() {
@@ -219,7 +130,7 @@ mod tests_day_20 {
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/20.in")), 5680);
+        assert_eq!(first_part(&real(2021, 20)), 5680);
     }
     #[test]
     fn test_example_second_part() {
@@ -228,6 +139,6 @@ mod tests_day_20 {
 
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/20.in")), 19766);
+        assert_eq!(second_part(&real(2021, 20)), 19766);
     }
 }
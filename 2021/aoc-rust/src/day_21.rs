@@ -1,11 +1,14 @@
 use std::{cmp, collections::HashMap, os::linux::raw::stat, ptr::addr_eq};
 
+use aoc_commons::memo::Memo;
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
 
 const WIN_THRESHOLD: i32 = 1000;
 const WIN_THRESHOLD_PART_2: i32 = 21;
 
+aoc_commons::solution!(2021, 21, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     let (p1, p2) = parse(input);
 
@@ -22,7 +25,7 @@ pub fn first_part(input: &str) -> i32 {
     while p1_score < WIN_THRESHOLD && p2_score < WIN_THRESHOLD {
 
         let mut addition = 0;
-        for i in 0..3 {
+        for _ in 0..3 {
             n_die_throws += 1;
             addition += die_state + 1;
             die_state = (die_state + 1) % 100;
@@ -45,14 +48,14 @@ pub fn first_part(input: &str) -> i32 {
 pub fn second_part(input: &str) -> i64 {
     let (p1, p2) = parse(input);
 
-    let mut memoization: FxHashMap<GameState, i64> = FxHashMap::default();
+    let mut memo = Memo::new();
     compute_p1_wins_recursively(GameState {
         p1_pos: p1 - 1,
         p1_score: 0,
         p2_pos: p2 - 1,
         p2_score: 0,
         is_p1s_turn: true,
-    }, &mut memoization)
+    }, &mut memo)
 }
 
 fn next_state_frequencies(state: GameState) -> FxHashMap<GameState, i64> {
@@ -92,32 +95,28 @@ fn next_state_frequencies(state: GameState) -> FxHashMap<GameState, i64> {
 
 fn compute_p1_wins_recursively(
     state: GameState,
-    memoization: &mut FxHashMap<GameState, i64>,
+    memo: &mut Memo<GameState, i64>,
 ) -> i64 {
-    if let Some(mem_value) = memoization.get(&state) {
-        return *mem_value;
-    }
-
-    let n_wins = next_state_frequencies(state)
-        .into_iter()
-        .map(|(next_state, freq)| {
-            if state.is_p1s_turn {
-                if next_state.is_over() {
-                    freq
+    memo.get_or_compute(state, |memo, _| {
+        next_state_frequencies(state)
+            .into_iter()
+            .map(|(next_state, freq)| {
+                if state.is_p1s_turn {
+                    if next_state.is_over() {
+                        freq
+                    } else {
+                        freq * compute_p1_wins_recursively(next_state, memo)
+                    }
                 } else {
-                    freq * compute_p1_wins_recursively(next_state, memoization)
+                    if next_state.is_over() {
+                        0
+                    } else {
+                        freq * compute_p1_wins_recursively(next_state, memo)
+                    }
                 }
-            } else {
-                if next_state.is_over() {
-                    0
-                } else {
-                    freq * compute_p1_wins_recursively(next_state, memoization)
-                }
-            }
-        })
-        .sum::<i64>();
-
-    memoization.entry(state).or_insert(n_wins).to_owned()
+            })
+            .sum::<i64>()
+    })
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
@@ -157,6 +156,7 @@ fn parse(input: &str) -> (i32, i32) {
 
 #[cfg(test)]
 mod tests_day_21 {
+    use aoc_commons::inputs::real;
     use std::collections::HashSet;
 
     use crate::day_21::{first_part, parse, second_part, GameState};
@@ -177,7 +177,7 @@ mod tests_day_21 {
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/21.in")), 605070);
+        assert_eq!(first_part(&real(2021, 21)), 605070);
     }
 
     #[test]
@@ -210,6 +210,6 @@ mod tests_day_21 {
     #[test]
     fn test_second_part() {
     
-        assert_eq!(second_part(include_str!("../inputs/21.in")), -1);
+        assert_eq!(second_part(&real(2021, 21)), -1);
     }
 }
@@ -1,154 +1,37 @@
-use std::{
-    cmp::{max, min},
-    ops::Range,
-};
-
 use itertools::Itertools;
 
-const WORLD_OFFSET: i32 = 50;
+use crate::box_set::{BoxSet, Cuboid};
+
+/// The initialization procedure only concerns cubes at most 50 from the origin.
+const INITIALIZATION_REGION: Cuboid = (-50..51, -50..51, -50..51);
+
+aoc_commons::solution!(2021, 22, first_part, second_part);
 
 pub fn first_part(input: &str) -> i64 {
-    build_world(&parse(input))
-        .into_iter()
-        .map(|c| clamp(&c))
-        .map(|c| volume(&c))
-        .sum::<i64>()
+    reboot(
+        &parse(input),
+        BoxSet::new().clamped_to(INITIALIZATION_REGION),
+    )
+    .volume()
 }
 pub fn second_part(input: &str) -> i64 {
-    build_world(&parse(input))
-        .into_iter()
-        .map(|c| volume(&c))
-        .sum::<i64>()
+    reboot(&parse(input), BoxSet::new()).volume()
 }
 
-type Cuboid = (Range<i32>, Range<i32>, Range<i32>);
-
 struct Operation {
     value: bool,
     cuboid: Cuboid,
 }
 
-fn offset_to_usize(r: &Range<i32>, offset: i32) -> Range<usize> {
-    (r.start + offset) as usize..(r.end + offset + 1) as usize
-}
-
-fn volume(c: &Cuboid) -> i64 {
-    let x =
-        (c.0.end - c.0.start) as i64 * (c.1.end - c.1.start) as i64 * (c.2.end - c.2.start) as i64;
-    if x < 0 {
-        println!("negative cuboid: {:?}", c);
-    }
-    x
-}
-
-fn clamp_range(r: &Range<i32>) -> Range<i32> {
-    min(max(r.start, -WORLD_OFFSET), WORLD_OFFSET + 1)
-        ..min(max(r.end, -WORLD_OFFSET), WORLD_OFFSET + 1)
-}
-
-fn clamp(c: &Cuboid) -> Cuboid {
-    (clamp_range(&c.0), clamp_range(&c.1), clamp_range(&c.2))
-}
-
-enum Intersection {
-    Double(Range<i32>),
-    Single(i32),
-}
-
-fn is_range_overlap(a: &Range<i32>, b: &Range<i32>) -> bool {
-    (b.start >= a.start && b.start < a.end)
-        || (b.end > a.start && b.end <= a.end)
-        || (a.start >= b.start && a.start < b.end)
-        || (a.end > b.start && a.end <= b.end)
-}
-
-fn is_cuboid_overlap(a: &Cuboid, b: &Cuboid) -> bool {
-    is_range_overlap(&a.0, &b.0) && is_range_overlap(&a.1, &b.1) && is_range_overlap(&a.2, &b.2)
-}
-
-fn cut_range(base: &Range<i32>, to_cut: &Range<i32>) -> (Range<i32>, Range<i32>) {
-    (
-        min(to_cut.start, base.start)..base.start,
-        base.end..max(to_cut.end, base.end),
-    )
-}
-
-// fn build_world(ops: &[Operation]) -> i32 {
-//     let mut world = vec![];
-
-//     for op in ops {
-//         let mut stack = vec![];
-
-//         let overlapping = world
-//             .iter()
-//             .filter(|b| is_cuboid_overlap(b, &op.cuboid))
-//             .collect::<Vec<_>>();
-
-//         for overlapping_cuboid in overlapping {}
-//     }
-
-//     todo!();
-// }
-
-fn modify_world_by_adding(world: &mut Vec<Cuboid>, new: &Cuboid) {
-    let mut stack = vec![new.clone()];
-    // let mut accumulator = vec![];
-
-    while let Some(current) = stack.pop() {
-        let world_overlap = world.iter().find(|b| is_cuboid_overlap(b, &current));
-
-        if let Some(overlap) = world_overlap {
-            cut_into_non_empty_cuboids(overlap, &current).for_each(|c| stack.push(c));
-        } else {
-            world.push(current);
-        }
-    }
-}
-
-fn cut_into_non_empty_cuboids(base: &Cuboid, to_cut: &Cuboid) -> impl Iterator<Item = Cuboid> {
-    let new_x = cut_range(&base.0, &to_cut.0);
-    let new_y = cut_range(&base.1, &to_cut.1);
-    let new_z = cut_range(&base.2, &to_cut.2);
-
-    [
-        (new_x.0, to_cut.1.clone(), to_cut.2.clone()),
-        (new_x.1, to_cut.1.clone(), to_cut.2.clone()),
-        (to_cut.0.clone(), new_y.0, to_cut.2.clone()),
-        (to_cut.0.clone(), new_y.1, to_cut.2.clone()),
-        (to_cut.0.clone(), to_cut.1.clone(), new_z.0),
-        (to_cut.0.clone(), to_cut.1.clone(), new_z.1),
-    ]
-    .into_iter()
-    .filter(|c| volume(c) > 0)
-}
-
-fn subtract_from_world(world: Vec<Cuboid>, new: &Cuboid) -> Vec<Cuboid> {
-    let mut new_world = Vec::with_capacity(world.len());
-
-    for existing in world.iter() {
-        if is_cuboid_overlap(new, existing) {
-            cut_into_non_empty_cuboids(new, existing)
-                .for_each(|c| modify_world_by_adding(&mut new_world, &c));
-        } else {
-            new_world.push(existing.clone());
-        }
-    }
-
-    new_world
-}
-
-fn build_world(ops: &[Operation]) -> Vec<Cuboid> {
-    let mut world = vec![];
-
+fn reboot(ops: &[Operation], mut cubes: BoxSet) -> BoxSet {
     for op in ops {
         if op.value {
-            modify_world_by_adding(&mut world, &op.cuboid);
+            cubes.union(&op.cuboid);
         } else {
-            world = subtract_from_world(world, &op.cuboid);
+            cubes.subtract(&op.cuboid);
         }
     }
-
-    world
+    cubes
 }
 
 fn parse(input: &str) -> Vec<Operation> {
@@ -179,6 +62,7 @@ fn parse(input: &str) -> Vec<Operation> {
 #[cfg(test)]
 mod tests_day_22 {
     use super::*;
+    use aoc_commons::inputs::real;
 
     const SMALL_EXAMPLE_INPUT: &str = "on x=10..12,y=10..12,z=10..12
 on x=11..13,y=11..13,z=11..13
@@ -208,6 +92,67 @@ on x=-41..9,y=-7..43,z=-33..15
 on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
 on x=967..23432,y=45373..81175,z=27513..53682";
 
+    const LARGER_EXAMPLE_INPUT: &str = "on x=-5..47,y=-31..22,z=-19..33
+on x=-44..5,y=-27..21,z=-14..35
+on x=-49..-1,y=-11..42,z=-10..38
+on x=-20..34,y=-40..6,z=-44..1
+off x=26..39,y=40..50,z=-2..11
+on x=-41..5,y=-41..6,z=-36..8
+off x=-43..-33,y=-45..-28,z=7..25
+on x=-33..15,y=-32..19,z=-34..11
+off x=35..47,y=-46..-34,z=-11..5
+on x=-14..36,y=-6..44,z=-16..29
+on x=-57795..-6158,y=29564..72030,z=20435..90618
+on x=36731..105352,y=-21140..28532,z=16094..90401
+on x=30999..107136,y=-53464..15513,z=8553..71215
+on x=13528..83982,y=-99403..-27377,z=-24141..23996
+on x=-72682..-12347,y=18159..111354,z=7391..80950
+on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
+on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
+on x=-52752..22273,y=-49450..9096,z=54442..119054
+on x=-29982..40483,y=-108474..-28371,z=-24328..38471
+on x=-4958..62750,y=40422..118853,z=-7672..65583
+on x=55694..108686,y=-43367..46958,z=-26781..48729
+on x=-98497..-18186,y=-63569..3412,z=1232..88485
+on x=-726..56291,y=-62629..13224,z=18033..85226
+on x=-110886..-34664,y=-81338..-8658,z=8914..63723
+on x=-55829..24974,y=-16897..54165,z=-121762..-28058
+on x=-65152..-11147,y=22489..91432,z=-58782..1780
+on x=-120100..-32970,y=-46592..27473,z=-11695..61039
+on x=-18631..37533,y=-124565..-50804,z=-35667..28308
+on x=-57817..18248,y=49321..117703,z=5745..55881
+on x=14781..98692,y=-1341..70827,z=15753..70151
+on x=-34419..55919,y=-19626..40991,z=39015..114138
+on x=-60785..11593,y=-56135..2999,z=-95368..-26915
+on x=-32178..58085,y=17647..101866,z=-91405..-8878
+on x=-53655..12091,y=50097..105568,z=-75335..-4862
+on x=-111166..-40997,y=-71714..2688,z=5609..50954
+on x=-16602..70118,y=-98693..-44401,z=5197..76897
+on x=16383..101554,y=4615..83635,z=-44907..18747
+off x=-95822..-15171,y=-19987..48940,z=10804..104439
+on x=-89813..-14614,y=16069..88491,z=-3297..45228
+on x=41075..99376,y=-20427..49978,z=-52012..13762
+on x=-21330..50085,y=-17944..62733,z=-112280..-30197
+on x=-16478..35915,y=36008..118594,z=-7885..47086
+off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
+off x=2032..69770,y=-71013..4824,z=7471..94418
+on x=43670..120875,y=-42068..12382,z=-24787..38892
+off x=37514..111226,y=-45862..25743,z=-16714..54663
+off x=25699..97951,y=-30668..59918,z=-15349..69697
+off x=-44271..17935,y=-9516..60759,z=49131..112598
+on x=-61695..-5813,y=40978..94975,z=8655..80240
+off x=-101086..-9439,y=-7088..67543,z=33935..83858
+off x=18020..114017,y=-48931..32606,z=21474..89843
+off x=-77139..10506,y=-89994..-18797,z=-80..59318
+off x=8476..79288,y=-75520..11602,z=-96624..-24783
+on x=-47488..-1262,y=24338..100707,z=16292..72967
+off x=-84341..13987,y=2429..92914,z=-90671..-1318
+off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
+off x=-27365..46395,y=31009..98017,z=15428..76570
+off x=-70369..-16548,y=22648..78696,z=-1892..86821
+on x=-53470..21291,y=-120233..-33476,z=-44150..38147
+off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
+
     #[test]
     fn test_parse() {
         let ops = parse(EXAMPLE_INPUT);
@@ -223,59 +168,48 @@ on x=967..23432,y=45373..81175,z=27513..53682";
 
     #[test]
     fn test_overlaps() {
-        let a = (0..3, 0..3, 0..3);
-        let b = (1..5, 0..3, 0..3);
-        let c = (-1..2, -300..-200, -300..-200);
-
-        assert_eq!(volume(&a), 27);
-        assert_eq!(volume(&b), 36);
-        assert_eq!(volume(&c), 30000);
-        assert!(is_cuboid_overlap(&a, &b));
-        assert!(!is_cuboid_overlap(&a, &c))
-    }
-
-    #[test]
-    fn test_cut_by_world() {
-        let mut world = vec![(0..3, 0..3, 0..3)];
-
-        modify_world_by_adding(&mut world, &(2..5, 2..5, 2..5));
-
+        // overlapping corners, switched on and then off again
+        let on = "on x=0..2,y=0..2,z=0..2\non x=2..4,y=2..4,z=2..4";
+        assert_eq!(second_part(on), 53);
         assert_eq!(
-            world,
-            vec![
-                (0..3, 0..3, 0..3),
-                (2..5, 2..5, 3..5),
-                (2..5, 3..5, 2..3),
-                (3..5, 2..3, 2..3)
-            ]
+            second_part("on x=0..2,y=0..2,z=0..2\noff x=2..4,y=2..4,z=2..4"),
+            26
+        );
+        assert_eq!(second_part(&format!("{on}\noff x=2..4,y=2..4,z=2..4")), 26);
+        assert_eq!(
+            second_part(&format!("{on}\non x=1..3,y=1..3,z=1..3")),
+            53 + 12
         );
-        assert_eq!(world.iter().map(volume).sum::<i64>(), 53);
-    }
-
-    #[test]
-    fn test_subtract_from_world() {
-        let world = vec![(0..3, 0..3, 0..3)];
 
-        let new_world = subtract_from_world(world, &(2..5, 2..5, 2..5));
+        // touching faces do not overlap, neither do far away cuboids
+        assert_eq!(
+            second_part("on x=0..2,y=0..2,z=0..2\non x=3..3,y=0..2,z=0..2"),
+            36
+        );
+        let far = "on x=0..2,y=0..2,z=0..2\non x=-1..1,y=-300..-201,z=-300..-201";
+        assert_eq!(second_part(far), 27 + 30000);
+        assert_eq!(first_part(far), 27);
 
+        // switching off what is not on changes nothing
         assert_eq!(
-            new_world,
-            vec![(0..2, 0..3, 0..3), (2..3, 0..2, 0..3), (2..3, 2..3, 0..2)]
+            second_part("off x=0..2,y=0..2,z=0..2\non x=0..0,y=0..0,z=0..0"),
+            1
         );
-        assert_eq!(new_world.iter().map(volume).sum::<i64>(), 26);
     }
 
     #[test]
     fn test_example_first_part() {
         assert_eq!(first_part(SMALL_EXAMPLE_INPUT), 39);
         assert_eq!(first_part(EXAMPLE_INPUT), 590784);
+        assert_eq!(first_part(LARGER_EXAMPLE_INPUT), 474140);
     }
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/22.in")), 647076);
+        assert_eq!(first_part(&real(2021, 22)), 647076);
     }
     #[test]
-    fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/22.in")), -1);
+    fn test_example_second_part() {
+        assert_eq!(second_part(SMALL_EXAMPLE_INPUT), 39);
+        assert_eq!(second_part(LARGER_EXAMPLE_INPUT), 2758514936282235);
     }
 }
@@ -1,29 +1,28 @@
 use std::ops::Range;
 
-use rustc_hash::FxHashSet;
+use aoc_commons::error::{parse_lines, SolveError};
+use aoc_commons::memo::Memo;
 
-pub fn first_part(input: &str) -> i64 {
-    find_model_number_trying_digits(
-        &parse(include_str!("../inputs/24.in")),
-        &[9, 8, 7, 6, 5, 4, 3, 2, 1],
-    )
+aoc_commons::solution!(2021, 24, first_part, second_part);
+
+pub fn first_part(input: &str) -> Result<i64, SolveError> {
+    find_model_number_trying_digits(&parse(input)?, &[9, 8, 7, 6, 5, 4, 3, 2, 1])
 }
-pub fn second_part(input: &str) -> i64 {
-    find_model_number_trying_digits(
-        &parse(include_str!("../inputs/24.in")),
-        &[1, 2, 3, 4, 5, 6, 7, 8, 9],
-    )
+pub fn second_part(input: &str) -> Result<i64, SolveError> {
+    find_model_number_trying_digits(&parse(input)?, &[1, 2, 3, 4, 5, 6, 7, 8, 9])
 }
 
-fn find_model_number_trying_digits(operations: &[Operation], digits: &[i32]) -> i64 {
+fn find_model_number_trying_digits(
+    operations: &[Operation],
+    digits: &[i32],
+) -> Result<i64, SolveError> {
     let modules = split_into_modules(operations);
-    let mut banned_states = FxHashSet::default();
-    find_model_number(0, 0, 0, &modules, &mut banned_states, digits).unwrap()
+    find_model_number(0, 0, &modules, &mut Memo::new(), digits)
+        .ok_or(SolveError::new("no model number is accepted by MONAD"))
 }
 
 type Registers = [i32; 4];
 
-const EMPTY_REGISTERS: Registers = [0, 0, 0, 0];
 const MAX_ALU_VALUE: i32 = 10_000_000;
 
 #[derive(Debug, Clone, Copy)]
@@ -95,53 +94,53 @@ impl Module {
     }
 }
 
-fn var_name_to_index(name: &str) -> usize {
-    if name == "x" {
-        0
-    } else if name == "y" {
-        1
-    } else if name == "z" {
-        2
-    } else if name == "w" {
-        3
-    } else {
-        panic!("Unexpected variable name '{}'", name);
+fn var_name_to_index(name: &str) -> Option<usize> {
+    match name {
+        "x" => Some(0),
+        "y" => Some(1),
+        "z" => Some(2),
+        "w" => Some(3),
+        _ => None,
     }
 }
 
-fn build_symbol(input: &str) -> Symbol {
-    if let Ok(number) = input.parse::<i32>() {
-        Symbol::Constant(number)
+fn build_symbol(line: &str, token: &str) -> Result<Symbol, SolveError> {
+    if let Ok(number) = token.parse::<i32>() {
+        Ok(Symbol::Constant(number))
     } else {
-        Symbol::VariableIndex(var_name_to_index(input))
+        var_name_to_index(token)
+            .map(Symbol::VariableIndex)
+            .ok_or_else(|| SolveError::invalid_token(line, token, "variable name"))
     }
 }
 
-fn parse_operation(input: &str) -> Operation {
-    let parts = input.split_whitespace().collect::<Vec<&str>>();
-    if input.starts_with("inp") {
-        Operation::Inp(build_symbol(parts[1]))
-    } else if input.starts_with("add") {
-        Operation::Add(build_symbol(parts[1]), build_symbol(parts[2]))
-    } else if input.starts_with("mul") {
-        Operation::Mul(build_symbol(parts[1]), build_symbol(parts[2]))
-    } else if input.starts_with("div") {
-        Operation::Div(build_symbol(parts[1]), build_symbol(parts[2]))
-    } else if input.starts_with("mod") {
-        Operation::Mod(build_symbol(parts[1]), build_symbol(parts[2]))
-    } else if input.starts_with("eql") {
-        Operation::Eql(build_symbol(parts[1]), build_symbol(parts[2]))
-    } else {
-        panic!("Unknown operation '{}'", parts[0])
+fn parse_operation(line: &str) -> Result<Operation, SolveError> {
+    let parts = line.split_whitespace().collect::<Vec<&str>>();
+    let operand = |i: usize| match parts.get(i) {
+        Some(token) => build_symbol(line, token),
+        None => Err(SolveError::new(format!(
+            "missing operand {} of '{}'",
+            i, parts[0]
+        ))),
+    };
+    let variable = |i: usize| match operand(i)? {
+        Symbol::Constant(_) => Err(SolveError::invalid_token(line, parts[i], "variable name")),
+        symbol => Ok(symbol),
+    };
+
+    match parts[0] {
+        "inp" => Ok(Operation::Inp(variable(1)?)),
+        "add" => Ok(Operation::Add(variable(1)?, operand(2)?)),
+        "mul" => Ok(Operation::Mul(variable(1)?, operand(2)?)),
+        "div" => Ok(Operation::Div(variable(1)?, operand(2)?)),
+        "mod" => Ok(Operation::Mod(variable(1)?, operand(2)?)),
+        "eql" => Ok(Operation::Eql(variable(1)?, operand(2)?)),
+        _ => Err(SolveError::invalid_token(line, parts[0], "operation")),
     }
 }
 
-fn parse(input: &str) -> Vec<Operation> {
-    input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
-        .map(parse_operation)
-        .collect::<Vec<Operation>>()
+fn parse(input: &str) -> Result<Vec<Operation>, SolveError> {
+    parse_lines(input, parse_operation)
 }
 
 fn split_into_modules(operations: &[Operation]) -> Vec<Module> {
@@ -161,74 +160,89 @@ fn split_into_modules(operations: &[Operation]) -> Vec<Module> {
     modules
 }
 
+/// Digits for the modules from `module_idx` on which bring `z_carry` down to
+/// zero, preferring `input_digits` in the given order.
 fn find_model_number(
-    model_number: i64,
     z_carry: i32,
     module_idx: usize,
     modules: &[Module],
-    banned_states: &mut FxHashSet<(i32, usize)>,
+    memo: &mut Memo<(i32, usize), Option<i64>>,
     input_digits: &[i32],
 ) -> Option<i64> {
-    if banned_states.contains(&(z_carry, module_idx)) {
-        return None;
-    }
+    memo.get_or_compute((z_carry, module_idx), |memo, _| {
+        if module_idx >= modules.len() {
+            return None;
+        }
 
-    if module_idx >= modules.len() {
-        return None;
-    }
+        if z_carry >= MAX_ALU_VALUE || z_carry <= -MAX_ALU_VALUE {
+            return None; // z_carry got out of hand -> ignore such states
+        }
 
-    if z_carry >= MAX_ALU_VALUE || z_carry <= -MAX_ALU_VALUE {
-        return None; // z_carry got out of hand -> ignore such states
-    }
+        let module = &modules[module_idx];
+        let n_remaining = (modules.len() - module_idx - 1) as u32;
 
-    let module = &modules[module_idx];
+        for input_digit in input_digits {
+            let registers = module.execute(z_carry, *input_digit);
+            let produced_z_val = registers[2];
+            let digit_value = (*input_digit as i64) * 10_i64.pow(n_remaining);
 
-    for input_digit in input_digits {
-        let registers = module.execute(z_carry, *input_digit);
-        let produced_z_val = registers[2];
-        let produced_model_number = model_number * 10 + (*input_digit) as i64;
+            if n_remaining == 0 && produced_z_val == 0 {
+                return Some(digit_value);
+            }
 
-        if module_idx == 13 && produced_z_val == 0 {
-            return Some(produced_model_number);
-        }
+            let remaining_digits =
+                find_model_number(produced_z_val, module_idx + 1, modules, memo, input_digits);
 
-        let new_model_number = find_model_number(
-            produced_model_number,
-            produced_z_val,
-            module_idx + 1,
-            modules,
-            banned_states,
-            input_digits,
-        );
-
-        if let Some(n) = new_model_number {
-            return Some(n);
+            if let Some(n) = remaining_digits {
+                return Some(digit_value + n);
+            }
         }
-    }
 
-    banned_states.insert((z_carry, module_idx));
-
-    None
+        None
+    })
 }
 
 #[cfg(test)]
 mod tests_day_24 {
 
     use super::*;
+    use aoc_commons::inputs::real;
 
     const EXAMPLE_INPUT: &str = "inp z\ninp x\nmul z 3\neql z x";
+    const EMPTY_REGISTERS: Registers = [0, 0, 0, 0];
 
     #[test]
     fn test_parse_operations() {
-        let operations = parse(EXAMPLE_INPUT);
+        let operations = parse(EXAMPLE_INPUT).unwrap();
         assert_eq!(operations.len(), 4);
         assert!(matches!(operations[0], Operation::Inp(_)));
         assert!(matches!(operations[3], Operation::Eql(_, _)));
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("inp z\nmul z  q").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: invalid variable name 'q'"
+        );
+        let error = parse("inp z\n\nsub z 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid operation 'sub'"
+        );
+        let error = parse("add 3 z").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: invalid variable name '3'"
+        );
+        let error = parse("eql x").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: missing operand 2 of 'eql'");
+    }
+
     #[test]
     fn test_modules() {
-        let modules = split_into_modules(&parse(EXAMPLE_INPUT));
+        let modules = split_into_modules(&parse(EXAMPLE_INPUT).unwrap());
         assert_eq!(modules.len(), 2);
         assert_eq!(modules[0].operations.len(), 1);
         assert_eq!(modules[1].operations.len(), 3);
@@ -236,18 +250,18 @@ mod tests_day_24 {
 
     #[test]
     fn test_execute() {
-        let operations = parse(EXAMPLE_INPUT);
+        let operations = parse(EXAMPLE_INPUT).unwrap();
         let registers = execute(&operations, &[1, 3], &EMPTY_REGISTERS);
         assert_eq!(registers, [3, 0, 1, 0]);
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/24.in")), 39924989499969);
+        assert_eq!(first_part(&real(2021, 24)), Ok(39924989499969));
     }
 
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/24.in")), -1);
+        assert_eq!(second_part(&real(2021, 24)), Ok(-1));
     }
 }
@@ -1,17 +1,19 @@
+use aoc_commons::error::SolveError;
+use aoc_commons::grid::{Boundary, Grid, Point};
+use aoc_commons::p;
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
 
-use crate::grid::{Grid, Point};
-use crate::p;
+aoc_commons::solution!(2021, 25, first_part, second_part);
 
-pub fn first_part(input: &str) -> i32 {
-    let mut world = parse(input);
+pub fn first_part(input: &str) -> Result<i32, SolveError> {
+    let mut world = parse(input)?;
 
     for (i, _) in std::iter::repeat(()).enumerate() {
         let x_has_moved = r#move(&mut world, &p!(1, 0), '>');
         let y_has_moved = r#move(&mut world, &p!(0, 1), 'v');
         if !(x_has_moved || y_has_moved) {
-            return i as i32 + 1;
+            return Ok(i as i32 + 1);
         }
     }
     unreachable!()
@@ -27,7 +29,7 @@ fn r#move(world: &mut Grid<char>, direction: &Point, target_cucumber: char) -> b
     // let mut can_move = Grid::full(world.width(), world.height(), false);
 
     for (p, &cucumber) in world.iter() {
-        if cucumber == target_cucumber && is_unoccupied(&bound(&(p + *direction), world), &world) {
+        if cucumber == target_cucumber && is_unoccupied(&(p + *direction), &world) {
             can_move.insert(p);
         }
     }
@@ -35,33 +37,28 @@ fn r#move(world: &mut Grid<char>, direction: &Point, target_cucumber: char) -> b
     for (x, y) in (0..world.width()).cartesian_product(0..world.height()) {
         let p = Point::new(x as i32, y as i32);
         if *world.at(&p).unwrap() == target_cucumber && can_move.contains(&p) {
-            let target_p = bound(&(p + *direction), world);
-            *world.at_mut(&target_p).unwrap() = target_cucumber;
+            *world.at_mut(&(p + *direction)).unwrap() = target_cucumber;
             *world.at_mut(&p).unwrap() = '.';
         }
     }
     !can_move.is_empty()
 }
 
-fn bound(p: &Point, g: &Grid<char>) -> Point {
-    Point::new(p.x % g.width() as i32, p.y % g.height() as i32)
-}
-
 fn is_unoccupied(p: &Point, g: &Grid<char>) -> bool {
     g.at(p).unwrap() == &'.'
 }
 
-fn parse(input: &str) -> Grid<char> {
-    Grid::from_rows(input.lines().map(|line| {
-        line.chars()
-            .filter(|&c| c == '.' || c == '>' || c == 'v')
-            .collect::<Vec<_>>()
-    }))
-    .unwrap()
+fn parse(input: &str) -> Result<Grid<char>, SolveError> {
+    let world = Grid::parse_chars(input, |c| match c {
+        '.' | '>' | 'v' => Ok(c),
+        _ => Err(format!("unexpected cell '{}'", c)),
+    })?;
+    Ok(world.with_boundary(Boundary::Wrap))
 }
 #[cfg(test)]
 mod tests_day_25 {
     use super::*;
+    use aoc_commons::inputs::real;
 
     const EXAMPLE_INPUT: &str = "..........
 .>v....v..
@@ -80,22 +77,22 @@ v.v..>>v.v
 
     #[test]
     fn test_parse() {
-        let g = parse(EXAMPLE_INPUT);
+        let g = parse(EXAMPLE_INPUT).unwrap();
 
         assert_eq!(g.width(), 10);
         assert_eq!(g.height(), 4);
     }
     #[test]
     fn test_first_part_example() {
-        assert_eq!(first_part(EXAMPLE_INPUT_2), 58);
+        assert_eq!(first_part(EXAMPLE_INPUT_2), Ok(58));
     }
 
     #[test]
     fn test_first_part() {
-        assert_eq!(first_part(include_str!("../inputs/25.in")), 516);
+        assert_eq!(first_part(&real(2021, 25)), Ok(516));
     }
     #[test]
     fn test_second_part() {
-        assert_eq!(second_part(include_str!("../inputs/25.in")), -1);
+        assert_eq!(second_part(&real(2021, 25)), -1);
     }
 }
@@ -1,4 +1,4 @@
-mod benchmark;
+mod box_set;
 mod day_04;
 mod day_05;
 mod day_06;
@@ -21,17 +21,37 @@ mod day_21;
 mod day_22;
 mod day_24;
 mod day_25;
-mod graph;
-mod grid;
 mod space;
 mod utils;
 
-use crate::benchmark::{benchmark_run, print_day, print_header};
+use aoc_commons::{cli, Solution};
+
+const YEAR: u16 = 2021;
+
+const SOLUTIONS: &[&dyn Solution] = &[
+    &day_04::Solver,
+    &day_05::Solver,
+    &day_06::Solver,
+    &day_07::Solver,
+    &day_08::Solver,
+    &day_09::Solver,
+    &day_10::Solver,
+    &day_11::Solver,
+    &day_12::Solver,
+    &day_13::Solver,
+    &day_14::Solver,
+    &day_15::Solver,
+    &day_16::Solver,
+    &day_17::Solver,
+    &day_18::Solver,
+    &day_19::Solver,
+    &day_20::Solver,
+    &day_21::Solver,
+    &day_22::Solver,
+    &day_24::Solver,
+    &day_25::Solver,
+];
 
 fn main() {
-    // benchmark_all!(
-    //     day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_12, day_13, day_14, day_15,
-    //     day_16, day_17, day_18, day_19, day_20, day_21, day_22, day_24, day_25
-    // )
-    benchmark_all!(day_11);
+    cli::main(YEAR, SOLUTIONS);
 }
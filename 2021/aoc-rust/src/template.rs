@@ -0,0 +1,27 @@
+use aoc_commons::error::SolveError;
+
+aoc_commons::solution!(2021, DAY_NUMBER, first_part, second_part);
+
+pub fn first_part(_input: &str) -> Result<i32, SolveError> {
+    Err(SolveError::new("not solved yet"))
+}
+
+pub fn second_part(_input: &str) -> Result<i32, SolveError> {
+    Err(SolveError::new("not solved yet"))
+}
+
+#[cfg(test)]
+mod tests_day_DAY {
+    use super::*;
+    use aoc_commons::inputs::example;
+
+    #[test]
+    fn test_example_first_part() {
+        assert_eq!(first_part(&example(2021, DAY_NUMBER, 1)), Ok(0));
+    }
+
+    #[test]
+    fn test_example_second_part() {
+        assert_eq!(second_part(&example(2021, DAY_NUMBER, 1)), Ok(0));
+    }
+}
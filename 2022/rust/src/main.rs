@@ -1,6 +1,15 @@
 mod task_01;
 mod task_02;
 
+use aoc_commons::{cli, Solution};
+
+const YEAR: u16 = 2022;
+
+const SOLUTIONS: &[&dyn Solution] = &[
+    &task_01::Solver,
+    &task_02::Solver,
+];
+
 fn main() {
-    task_02::solve()
-}
\ No newline at end of file
+    cli::main(YEAR, SOLUTIONS);
+}
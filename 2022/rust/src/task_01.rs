@@ -1,5 +1,7 @@
-pub fn solve() {
-    let calories = include_str!("../inputs/01_1")
+aoc_commons::solution!(2022, 1, first_part, second_part);
+
+fn elven_sums(input: &str) -> Vec<u32> {
+    let calories = input
     .lines()
     .map(|line| line.parse::<u32>().ok())
     .collect::<Vec<_>>();
@@ -9,7 +11,14 @@ pub fn solve() {
         .sum::<u32>()).collect::<Vec<_>>();
     elven_sums.sort();
     elven_sums.reverse();
-    let sum_of_top_three_elfs: u32 = elven_sums.iter().take(3).sum();
-    println!("{sum_of_top_three_elfs}");
+    elven_sums
+}
+
+pub fn first_part(input: &str) -> u32 {
+    elven_sums(input)[0]
+}
+
+pub fn second_part(input: &str) -> u32 {
+    let sum_of_top_three_elfs: u32 = elven_sums(input).iter().take(3).sum();
+    sum_of_top_three_elfs
 }
-    
\ No newline at end of file
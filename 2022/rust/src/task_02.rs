@@ -1,4 +1,5 @@
 
+use aoc_commons::error::{parse_lines, SolveError};
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 enum Move {
@@ -47,30 +48,39 @@ fn play(first: Move, second: Move) -> Outcome {
     }
 }
 
-fn letter_to_move(s: &str) -> Move {
+fn letter_to_move(line: &str, s: &str) -> Result<Move, SolveError> {
     match s {
-        "A" | "X" => Move::ROCK,
-        "B" | "Y" => Move::PAPER,
-        "C" | "Z" => Move::SCISSORS,
-        _ => panic!("Invalid game move {}", s)
+        "A" | "X" => Ok(Move::ROCK),
+        "B" | "Y" => Ok(Move::PAPER),
+        "C" | "Z" => Ok(Move::SCISSORS),
+        _ => Err(SolveError::invalid_token(line, s, "game move"))
     }
 }
 
-fn letter_to_outcome(s: &str) -> Outcome {
+fn letter_to_outcome(line: &str, s: &str) -> Result<Outcome, SolveError> {
     match s {
-        "X" => Outcome::LOSS,
-        "Y" => Outcome::DRAW,
-        "Z" => Outcome::WIN,
-        _ => panic!("Invalid game outcome {}", s)
+        "X" => Ok(Outcome::LOSS),
+        "Y" => Ok(Outcome::DRAW),
+        "Z" => Ok(Outcome::WIN),
+        _ => Err(SolveError::invalid_token(line, s, "game outcome"))
     } 
 }
 
-fn line_to_moves(line: &str) -> (Move, Move) {
-    return (letter_to_move(&line[0..1]), letter_to_move(&line[2..3]));
+fn split_line(line: &str) -> Result<(&str, &str), SolveError> {
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [first, second] => Ok((first, second)),
+        _ => Err(SolveError::new(format!("expected two letters, got '{}'", line)))
+    }
+}
+
+fn line_to_moves(line: &str) -> Result<(Move, Move), SolveError> {
+    let (first, second) = split_line(line)?;
+    Ok((letter_to_move(line, first)?, letter_to_move(line, second)?))
 }
 
-fn line_to_move_outcome(line: &str) -> (Move, Outcome) {
-    return (letter_to_move(&line[0..1]), letter_to_outcome(&line[2..3]))
+fn line_to_move_outcome(line: &str) -> Result<(Move, Outcome), SolveError> {
+    let (first, second) = split_line(line)?;
+    Ok((letter_to_move(line, first)?, letter_to_outcome(line, second)?))
 }
 
 fn play_until_outcome(first: Move, wanted_outcome: Outcome) -> Move {
@@ -83,19 +93,48 @@ fn play_until_outcome(first: Move, wanted_outcome: Outcome) -> Move {
 }
 
 
-pub fn solve() {
-    let move_outcome_vec = include_str!("../inputs/02_1")
-    .lines()
-    .map(line_to_move_outcome)
-    .collect::<Vec<_>>();
-    // println!("{:?}", move_outcome_vec);
-    
+aoc_commons::solution!(2022, 2, first_part, second_part);
+
+pub fn first_part(input: &str) -> Result<i32, SolveError> {
+    let moves_vec = parse_lines(input, line_to_moves)?;
+
+    Ok(moves_vec
+    .iter()
+    .map(|m| play(m.0, m.1).score() + m.1.score())
+    .sum())
+}
+
+pub fn second_part(input: &str) -> Result<i32, SolveError> {
+    let move_outcome_vec = parse_lines(input, line_to_move_outcome)?;
+
     let scores = move_outcome_vec
     .iter()
     .map(|m| 
         play_until_outcome(m.0, m.1).score() + m.1.score())
     .collect::<Vec<_>>();
-    // println!("{scores:?}");
 
-    println!("{:?}", scores.iter().sum::<i32>())
-}
\ No newline at end of file
+    Ok(scores.iter().sum::<i32>())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::task_02::{first_part, second_part};
+
+    const EXAMPLE_INPUT: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(first_part(EXAMPLE_INPUT), Ok(15));
+        assert_eq!(second_part(EXAMPLE_INPUT), Ok(12));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = first_part("A Y\nB Q\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: invalid game move 'Q'");
+        let error = second_part("A Y\nB X\nD Z\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: invalid game move 'D'");
+        assert!(second_part("A W").unwrap_err().to_string().contains("game outcome 'W'"));
+        assert!(first_part("A").is_err());
+    }
+}
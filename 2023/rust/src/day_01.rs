@@ -3,8 +3,10 @@ fn get_digits(line: &str) -> String {
     line.chars().filter(|c| c.is_numeric()).collect()
 }
 
-pub fn first_part() -> i32 {
-    include_str!("inputs/01.secret").lines().map(|l| get_calibration(l, get_digits)).sum()
+aoc_commons::solution!(2023, 1, first_part, second_part);
+
+pub fn first_part(input: &str) -> i32 {
+    input.lines().map(|l| get_calibration(l, get_digits)).sum()
 }
 
 
@@ -63,13 +65,14 @@ fn get_calibration(line: &str, digit_extractor: fn (&str) -> String) -> i32 {
 }
 
 
-pub fn second_part() -> i32 {
-    include_str!("inputs/01.secret").lines().map(|l| get_calibration(l, get_digits_from_words)).sum()    
+pub fn second_part(input: &str) -> i32 {
+    input.lines().map(|l| get_calibration(l, get_digits_from_words)).sum()    
 }
 
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::real;
     use crate::day_01::{parse_digit, first_part, second_part};
 
     #[test]
@@ -79,7 +82,7 @@ mod tests {
 
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(), 53334);
-        assert_eq!(second_part(), 52834);
+        assert_eq!(first_part(&real(2023, 1)), 53334);
+        assert_eq!(second_part(&real(2023, 1)), 52834);
     }
 }
\ No newline at end of file
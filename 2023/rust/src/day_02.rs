@@ -69,6 +69,8 @@ fn is_possible(game: &Game) -> bool {
 
 
 
+aoc_commons::solution!(2023, 2, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     input.split("\n").map(|line| parse_line(line)).filter(is_possible).map(|g| g.name).sum()
 }
@@ -80,11 +82,12 @@ pub fn second_part(input: &str) -> i32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_02::{first_part, second_part, parse_line, Game, Throw, is_possible};
     
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/02_example_1.txt")), 8);
+        assert_eq!(first_part(&example(2023, 2, 1)), 8);
     }
 
     #[test]
@@ -105,7 +108,7 @@ mod tests {
     
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/02.secret")), 2164);
-        assert_eq!(second_part(include_str!("inputs/02.secret")), 69929);
+        assert_eq!(first_part(&real(2023, 2)), 2164);
+        assert_eq!(second_part(&real(2023, 2)), 69929);
     }
 }
\ No newline at end of file
@@ -23,6 +23,8 @@ fn compute_ways_for_races(input: &str, parsing_fn: fn(&str) -> Vec<u64>) -> i32
     times.iter().zip(distances_to_beat.iter()).map(|it| count_ways(*it.0, *it.1)).product()
 }
 
+aoc_commons::solution!(2023, 6, first_part, second_part);
+
 pub fn first_part(input: &str) -> i32 {
     compute_ways_for_races(input, parse_numbers)
 }
@@ -33,11 +35,13 @@ pub fn second_part(input: &str) -> i32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_06::{first_part, second_part, parse_numbers, count_ways, parse_number};
 
     #[test]
     fn test_parsing() {
-        let lines: Vec<&str> = include_str!("inputs/06_example_1.txt").lines().collect();
+        let input = example(2023, 6, 1);
+        let lines: Vec<&str> = input.lines().collect();
         let times = parse_numbers(lines[0].split_once(":").unwrap().1);
         let distances_to_beat = parse_numbers(lines[1].split_once(":").unwrap().1);
         assert_eq!(times, vec![7, 15, 30]);
@@ -50,13 +54,13 @@ mod tests {
     #[test]
     fn test_example() {
         assert_eq!(count_ways(7, 9), 4);
-        assert_eq!(first_part(include_str!("inputs/06_example_1.txt")), 288);
-        assert_eq!(second_part(include_str!("inputs/06_example_1.txt")), 71503);
+        assert_eq!(first_part(&example(2023, 6, 1)), 288);
+        assert_eq!(second_part(&example(2023, 6, 1)), 71503);
     }
     
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/06.secret")), 32076);
-        // assert_eq!(second_part(include_str!("inputs/06.secret")), 0);
+        assert_eq!(first_part(&real(2023, 6)), 32076);
+        // assert_eq!(second_part(&real(2023, 6)), 0);
     }
 }
\ No newline at end of file
@@ -171,6 +171,8 @@ fn parse_game_turns(input: &str, parsing_fn: fn(&str) -> ([C;5], i64)) -> Vec<([
     .collect()
 }
 
+aoc_commons::solution!(2023, 7, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     let mut game_turns = parse_game_turns(input, parse_input);
     
@@ -200,6 +202,7 @@ pub fn second_part(input: &str) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_07::*;
     
     #[test]
@@ -243,7 +246,7 @@ mod tests {
     #[test]
     fn test_example() {
         
-        let mut game_turns = parse_game_turns(include_str!("inputs/07_example_1.txt"), parse_input);
+        let mut game_turns = parse_game_turns(&example(2023, 7, 1), parse_input);
         game_turns.sort_by(|lhs, rhs| compare_by_strength(&lhs.0, &rhs.0));
         assert_eq!(
             game_turns,
@@ -255,7 +258,7 @@ mod tests {
                 ([C::Q, C::Q, C::Q, C::J, C::A], 483),
             ]
         );
-        let mut joker_game_turns = parse_game_turns(include_str!("inputs/07_example_1.txt"), parse_input_and_swap_joker);
+        let mut joker_game_turns = parse_game_turns(&example(2023, 7, 1), parse_input_and_swap_joker);
         println!("{joker_game_turns:?}");
         joker_game_turns.sort_by(|lhs: &([C; 5], i64), rhs| compare_by_all_possible_strengths(&lhs.0, &rhs.0));
         assert_eq!(
@@ -268,8 +271,8 @@ mod tests {
                 ([C::K, C::T, C::N1, C::N1, C::T], 220),
             ]
         );
-        assert_eq!(first_part(include_str!("inputs/07_example_1.txt")), 6440);
-        assert_eq!(second_part(include_str!("inputs/07_example_1.txt")), 5905);
+        assert_eq!(first_part(&example(2023, 7, 1)), 6440);
+        assert_eq!(second_part(&example(2023, 7, 1)), 5905);
     }
 
     #[test]
@@ -286,7 +289,7 @@ mod tests {
     
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/07.secret")), 253313241);
-        // assert_eq!(second_part(include_str!("inputs/07.secret")), 253362743);  // takes too long to run each time
+        assert_eq!(first_part(&real(2023, 7)), 253313241);
+        // assert_eq!(second_part(&real(2023, 7)), 253362743);  // takes too long to run each time
     }
 }
\ No newline at end of file
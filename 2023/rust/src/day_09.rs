@@ -17,6 +17,8 @@ fn compute_next_value(sequence: &Vec<i64>) -> i64 {
     compute_next_value(&differences) + last
 }
 
+aoc_commons::solution!(2023, 9, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     input.split("\n")
     .filter(|l| !l.trim().is_empty())
@@ -44,6 +46,7 @@ pub fn second_part(input: &str) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_09::{first_part, second_part, compute_difference, compute_next_value};
     
 
@@ -77,13 +80,13 @@ mod tests {
 
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/09_example_1.txt")), 114);
-        assert_eq!(second_part(include_str!("inputs/09_example_1.txt")), 2);
+        assert_eq!(first_part(&example(2023, 9, 1)), 114);
+        assert_eq!(second_part(&example(2023, 9, 1)), 2);
     }
     
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/09.secret")), 1930746032);
-        assert_eq!(second_part(include_str!("inputs/09.secret")), 1154);
+        assert_eq!(first_part(&real(2023, 9)), 1930746032);
+        assert_eq!(second_part(&real(2023, 9)), 1154);
     }
 }
\ No newline at end of file
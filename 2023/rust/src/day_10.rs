@@ -1,5 +1,7 @@
-use std::{ops::Index, collections::{VecDeque, HashSet, HashMap, hash_set}, vec, fmt};
+use std::{ops::Index, collections::{HashSet, HashMap, hash_set}, vec, fmt};
 
+use aoc_commons::flood::{bfs, flood_fill};
+use aoc_commons::grid::Point;
 use itertools::Itertools;
 
 
@@ -28,39 +30,26 @@ impl Tile {
             x => panic!("Unknown input ({x:?})")
         }
     }
+
+    fn symbol(&self) -> char {
+        match &self {
+            Tile::DOT => '.',
+            Tile::CF => 'F',
+            Tile::CJ => 'J',
+            Tile::CL => 'L',
+            Tile::C7 => '7',
+            Tile::VERT => '|',
+            Tile::HOR => '-',
+            Tile::START => 'S'
+        }
+    }
 }
 impl Default for Tile {
     fn default() -> Self { Tile::DOT }
 }
 impl fmt::Debug for Tile {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match &self {
-            Tile::DOT => write!(f, "."),
-            Tile::CF => write!(f, "F"),
-            Tile::CJ => write!(f, "J"),
-            Tile::CL => write!(f, "L"),
-            Tile::C7 => write!(f, "7"),
-            Tile::VERT => write!(f, "|"),
-            Tile::HOR => write!(f, "-"),
-            Tile::START => write!(f, "S")
-        }
-    }
-}
-
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-struct Point {
-    x: i32,
-    y: i32
-}
-
-impl Point {
-    fn four_adjacent(&self) -> [Point;4] {
-        [
-            Point{x: self.x + 1, y: self.y + 0},
-            Point{x: self.x - 1, y: self.y + 0},
-            Point{x: self.x + 0, y: self.y + 1},
-            Point{x: self.x + 0, y: self.y - 1},
-        ]
+        write!(f, "{}", self.symbol())
     }
 }
 
@@ -167,42 +156,15 @@ fn get_adjacent_pipes(point: Point, rows: &Vec<Vec<Tile>>) -> Vec<Point> {
 }
 
 fn get_adjacent_dots(point: Point, rows: &Vec<Vec<Tile>>) -> Vec<Point> {
-    point.four_adjacent()
+    [Point::RIGHT, Point::LEFT, Point::DOWN, Point::UP]
     .iter()
+    .map(|d| point + *d)
     .filter(|p| is_contained(p, rows))
     .filter(|p| matches!(rows[p.y as usize][p.x as usize], Tile::DOT))
-    .cloned()
     .collect_vec()
 }
 
-fn find_distances(starts: &Vec<Point>, rows: &Vec<Vec<Tile>>, adjacent_fn: fn(Point, &Vec<Vec<Tile>>) -> Vec<Point>) -> HashMap<Point, i32> {
-
-    let mut queue: VecDeque<Point> = VecDeque::default();
-    let mut visited: HashSet<Point> = HashSet::default();
-    let mut distances: HashMap<Point, i32> = HashMap::default();
-
-    for s in starts {
-        distances.insert(*s, 0);
-        queue.push_back(s.clone());
-    }
-
-    while ! queue.is_empty()
-    {
-        let current = queue.pop_front().unwrap();
-        let current_distance = distances[&current];
-        // println!("Current {current:?} with distance {current_distance:?}");
-        for p in adjacent_fn(current, rows).iter() {
-            if ! visited.contains(p) {
-                // println!("Adj {p:?}");
-                visited.insert(p.clone());
-                distances.insert(*p, current_distance + 1);
-                queue.push_back(p.clone());
-            }
-        }
-    }
-
-    distances
-}
+aoc_commons::solution!(2023, 10, first_part, second_part);
 
 pub fn first_part(input: &str) -> i32 {
     let rows: Vec<Vec<Tile>> = input
@@ -215,11 +177,11 @@ pub fn first_part(input: &str) -> i32 {
 
     let fixed_rows = fix_start(start, rows);
 
-    let distances = find_distances(&vec![start], &fixed_rows, get_adjacent_pipes);
-    *distances.values().max().unwrap()
+    let distances = bfs([start], |p| get_adjacent_pipes(*p, &fixed_rows));
+    *distances.values().max().unwrap() as i32
 }
 
-fn enlarge(rows: &Vec<Vec<Tile>>, distances: &HashMap<Point, i32>) -> Vec<Vec<Tile>> {
+fn enlarge(rows: &Vec<Vec<Tile>>, distances: &HashMap<Point, usize>) -> Vec<Vec<Tile>> {
     let mut enlarged: Vec<Vec<Tile>> = vec![vec![Tile::default(); rows.first().unwrap().len() * 3];rows.len() * 3];
     
     for (y, row) in rows.iter().enumerate() {
@@ -281,56 +243,29 @@ fn get_edge_points(rows: &Vec<Vec<Tile>>) -> Vec<Point> {
     edge_points
 }
 
-fn print_rows(rows: &Vec<Vec<Tile>>, marked_points: Option<&HashSet<&Point>>) {
-    for (y, row) in rows.iter().enumerate() {
-        for (x, _) in row.iter().enumerate() {
-            let t = rows[y as usize][x as usize];
-            let p = Point{x: x as i32, y: y as i32};
-            if marked_points.unwrap_or(&HashSet::new()).contains(&p) {
-                print!("x");
-            } else {
-                print!("{t:?}");
-            }
-        }
-        println!("")
-    }
-}
-
 pub fn second_part(input: &str) -> i32 {
     let rows: Vec<Vec<Tile>> = input
     .split("\n")
     .filter(|l| !l.trim().is_empty())
     .map(parse_row)
     .collect();
-    println!("rows:");
-    print_rows(&rows, None);
-
 
     let start = extract_start(&rows);
     let fixed_rows = fix_start(start, rows);
-    println!("fixed rows:");
-    print_rows(&fixed_rows, None);
 
-    let pipe_distances = find_distances(&vec![start], &fixed_rows, get_adjacent_pipes);
+    let pipe_distances = bfs([start], |p| get_adjacent_pipes(*p, &fixed_rows));
 
     let enlarged: Vec<Vec<Tile>> = enlarge(&fixed_rows, &pipe_distances);
-    println!("enlarged:");
-    print_rows(&enlarged, None);
 
-
-    let reachable_from_edges: HashMap<Point, i32> = find_distances(&get_edge_points(&enlarged), &enlarged, get_adjacent_dots);
+    let reachable_from_edges: HashSet<Point> = flood_fill(get_edge_points(&enlarged), |p| get_adjacent_dots(*p, &enlarged));
     // println!("reachable_from_edges: {reachable_from_edges:?}");
 
-    println!("reachable:");
-    print_rows(&enlarged, Some(&HashSet::from_iter(reachable_from_edges.keys())));
-
-
     let mut dot_sum = 0;
     for (y, row) in fixed_rows.iter().enumerate() {
         for (x, original_tile) in row.iter().enumerate() {
             let enlarged_point = Point{x: (x as i32 * 3) + 1, y: (y as i32 * 3) + 1};
 
-            if !pipe_distances.contains_key(&Point { x: x as i32, y: y as i32}) && !reachable_from_edges.contains_key(&enlarged_point) {
+            if !pipe_distances.contains_key(&Point { x: x as i32, y: y as i32}) && !reachable_from_edges.contains(&enlarged_point) {
                 dot_sum += 1;
             }       
         }
@@ -340,25 +275,26 @@ pub fn second_part(input: &str) -> i32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_10::*;
     
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/10_example_1.txt")), 4);
-        assert_eq!(first_part(include_str!("inputs/10_example_2.txt")), 8);
+        assert_eq!(first_part(&example(2023, 10, 1)), 4);
+        assert_eq!(first_part(&example(2023, 10, 2)), 8);
     }
 
     #[test]
     fn test_example_2() {
-        assert_eq!(second_part(include_str!("inputs/10_example_1.txt")), 1);
-        assert_eq!(second_part(include_str!("inputs/10_example_2.txt")), 1);
-        assert_eq!(second_part(include_str!("inputs/10_example_3.txt")), 4);
-        assert_eq!(second_part(include_str!("inputs/10_example_4.txt")), 10);
+        assert_eq!(second_part(&example(2023, 10, 1)), 1);
+        assert_eq!(second_part(&example(2023, 10, 2)), 1);
+        assert_eq!(second_part(&example(2023, 10, 3)), 4);
+        assert_eq!(second_part(&example(2023, 10, 4)), 10);
     }
 
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/10.secret")), 6725);
-        assert_eq!(second_part(include_str!("inputs/10.secret")), 383);
+        assert_eq!(first_part(&real(2023, 10)), 6725);
+        assert_eq!(second_part(&real(2023, 10)), 383);
     }
 }
\ No newline at end of file
@@ -12,6 +12,8 @@ fn aoc_hash(text: &str) -> i64 {
     hash
 }
 
+aoc_commons::solution!(2023, 15, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     input.replace("\n", "").split(",").map(
         |text| aoc_hash(text)
@@ -75,6 +77,7 @@ pub fn second_part(input: &str) -> i32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_15::{first_part, second_part, aoc_hash};
     
     #[test]
@@ -86,13 +89,13 @@ mod tests {
 
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/15_example_1.txt")), 1320);
-        assert_eq!(second_part(include_str!("inputs/15_example_1.txt")), 145);
+        assert_eq!(first_part(&example(2023, 15, 1)), 1320);
+        assert_eq!(second_part(&example(2023, 15, 1)), 145);
     }
     
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/15.secret")), 515495);
-        assert_eq!(second_part(include_str!("inputs/15.secret")), 229349);
+        assert_eq!(first_part(&real(2023, 15)), 515495);
+        assert_eq!(second_part(&real(2023, 15)), 229349);
     }
 }
\ No newline at end of file
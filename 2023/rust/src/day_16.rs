@@ -1,29 +1,22 @@
 use std::collections::{HashSet, HashMap};
 use tqdm::Iter;
 
+use aoc_commons::direction::Direction;
+use aoc_commons::error::SolveError;
+use aoc_commons::grid::{Grid, Point};
 
-type V2 = (i32, i32);
 
-fn show(mp: &Vec<Vec<bool>>) {
-    for (y, row) in mp.iter().enumerate() {
-        for (x, cell) in row.iter().enumerate() {
-            match cell {
-                true => print!("#"),
-                false => print!(".")
-            }
-        }
-        println!();
-    }
-}
+type V2 = Point;
+type Beam = (V2, Direction);
 
-fn simulate_beams(world: &Vec<Vec<char>>, start_beam: (V2, V2)) -> i64 {
+fn simulate_beams(world: &Grid<char>, start_beam: Beam) -> i64 {
 
-    let (w, h) = (world.iter().next().unwrap().len() as i32, world.len() as i32);
+    let (w, h) = (world.width() as i32, world.height() as i32);
 
-    let mut beams: Vec<(V2, V2)> = vec![];
+    let mut beams: Vec<Beam> = vec![];
     beams.push(start_beam.clone());
 
-    let mut energized_map: Vec<Vec<bool>> = vec![vec![false; w as usize]; h as usize];
+    let mut energized_map: Grid<bool> = Grid::full(w as usize, h as usize, false);
     let mut locked_splitters: HashSet<V2> = HashSet::new();
 
     let mut n_changeless = 0;
@@ -34,18 +27,18 @@ fn simulate_beams(world: &Vec<Vec<char>>, start_beam: (V2, V2)) -> i64 {
         // let some_count = energized_map.iter().flatten().filter(|x| **x).count();
         // println!("{some_count:?}");
         // println!("{n_iterations}");
-        // show(&energized_map);
+        // print!("{}", energized_map.render(|e| if *e { '#' } else { '.' }));
 
         let mut change = false;
 
         for b in beams.iter() {
             let (b_pos, b_dir) = b;
 
-            let e = energized_map[b_pos.1 as usize][b_pos.0 as usize];
-            if ! e {
+            let e = energized_map.at_mut(b_pos).unwrap();
+            if ! *e {
                 change = true;
             }
-            energized_map[b_pos.1 as usize][b_pos.0 as usize] = true;
+            *e = true;
         }
         if change {
             n_changeless = 0;
@@ -54,98 +47,104 @@ fn simulate_beams(world: &Vec<Vec<char>>, start_beam: (V2, V2)) -> i64 {
         }
         
 
-        let mut tmp_beams: Vec<(V2, V2)> = vec![];
+        let mut tmp_beams: Vec<Beam> = vec![];
         for b in beams.iter() {
             let (b_pos, b_dir) = b;
 
-            let cell = world[b_pos.1 as usize][b_pos.0 as usize];
+            let cell = *world.at(b_pos).unwrap();
 
-            let mut new_beams: Vec<(V2, V2)> = match (cell, b_dir) {
+            let mut new_beams: Vec<Beam> = match (cell, b_dir) {
                 ('.', _) => vec![b.clone()],
-                ('-', (_, 0)) => vec![b.clone()],
-                ('-', (0, _)) => {
+                ('-', d) if d.is_horizontal() => vec![b.clone()],
+                ('-', _) => {
                     if locked_splitters.contains(b_pos) {
                         vec![]
                     } else {
                         locked_splitters.insert(b_pos.clone());
-                        vec![(b_pos.clone(), (1, 0)), (b_pos.clone(), (-1, 0))]
+                        vec![(b_pos.clone(), Direction::Right), (b_pos.clone(), Direction::Left)]
                     }
                 },
-                ('|', (0, _)) => vec![b.clone()],
-                ('|', (_, 0)) => {
+                ('|', d) if !d.is_horizontal() => vec![b.clone()],
+                ('|', _) => {
                     if locked_splitters.contains(b_pos) {
                         vec![]
                     } else {
                         locked_splitters.insert(b_pos.clone());
-                        vec![(b_pos.clone(), (0, 1)), (b_pos.clone(), (0, -1))]
+                        vec![(b_pos.clone(), Direction::Down), (b_pos.clone(), Direction::Up)]
                     }
                 },
-                ('/', (dx, dy)) => vec![(b_pos.clone(), (-dy.clone(), -dx.clone()))],
-                ('\\', (dx, dy)) => vec![(b_pos.clone(), (dy.clone(), dx.clone()))],
+                ('/', d) if d.is_horizontal() => vec![(b_pos.clone(), d.turn_left())],
+                ('/', d) => vec![(b_pos.clone(), d.turn_right())],
+                ('\\', d) if d.is_horizontal() => vec![(b_pos.clone(), d.turn_right())],
+                ('\\', d) => vec![(b_pos.clone(), d.turn_left())],
                 _ => panic!("Unexpected state ({cell:?}, {b_dir:?})")
             };
             tmp_beams.append(&mut new_beams);
         }
 
-        let moved_beams: Vec<(V2, V2)> = tmp_beams
+        let moved_beams: Vec<Beam> = tmp_beams
         .into_iter()
         .map(
-            |(p, d)| ((p.0 + d.0, p.1 + d.1), d)
+            |(p, d)| (p + d, d)
         )
-        .filter(|(p, d)| 0 <= p.0 && p.0 < w && 0 <= p.1 && p.1 < h)
+        .filter(|(p, d)| world.contains(p))
         .collect();
         
         beams = moved_beams;
     }
 
-    energized_map.iter().flatten().filter(|x| **x).count() as i64
+    energized_map.iter_values().filter(|x| **x).count() as i64
 }
 
-fn parse_world(text: &str) -> Vec<Vec<char>> {
-    text.split("\n").filter(|l| !l.is_empty()).map(
-        |line| line.chars().collect()
-    ).collect()
+fn parse_world(text: &str) -> Result<Grid<char>, SolveError> {
+    Grid::parse_chars(text, |c| match c {
+        '.' | '-' | '|' | '/' | '\\' => Ok(c),
+        _ => Err(format!("unknown cell {c:?}"))
+    })
 }
 
-pub fn first_part(input: &str) -> i64 {
-    let world = parse_world(input);
-    simulate_beams(&world, ((0, 0), (1, 0)))
+aoc_commons::solution!(2023, 16, first_part, second_part);
+
+pub fn first_part(input: &str) -> Result<i64, SolveError> {
+    let world = parse_world(input)?;
+    Ok(simulate_beams(&world, (V2::ZERO, Direction::Right)))
 }
 
-pub fn second_part(input: &str) -> i64 {
-    let world = parse_world(input);
+pub fn second_part(input: &str) -> Result<i64, SolveError> {
+    let world = parse_world(input)?;
 
-    let (w, h) = (world.iter().next().unwrap().len() as i32, world.len() as i32);
+    let (w, h) = (world.width() as i32, world.height() as i32);
 
-    let mut beams: Vec<(V2, V2)> = vec![];
+    let mut beams: Vec<Beam> = vec![];
 
     for x in 0..w {
-        beams.push(((x, 0), (0, 1)));
-        beams.push(((x, h - 1), (0, -1)));
+        beams.push((V2::new(x, 0), Direction::Down));
+        beams.push((V2::new(x, h - 1), Direction::Up));
     }
     for y in 0..h {
-        beams.push(((0, y), (1, 0)));
-        beams.push(((w - 1, y), (-1, 0)));
+        beams.push((V2::new(0, y), Direction::Right));
+        beams.push((V2::new(w - 1, y), Direction::Left));
     }
 
-    beams.iter().tqdm().map(
+    Ok(beams.iter().tqdm().map(
         |b| simulate_beams(&world, b.clone())
-    ).max().unwrap()
+    ).max().unwrap())
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_16::{first_part, second_part};
     
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/16_example_1.txt")), 46);
-        assert_eq!(second_part(include_str!("inputs/16_example_1.txt")), 51);
+        assert_eq!(first_part(&example(2023, 16, 1)), Ok(46));
+        assert_eq!(second_part(&example(2023, 16, 1)), Ok(51));
     }
     
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/16.secret")), 7996);
-        // assert_eq!(second_part(include_str!("inputs/16.secret")), 0);
+        assert_eq!(first_part(&real(2023, 16)), Ok(7996));
+        // assert_eq!(second_part(&real(2023, 16)), 0);
     }
 }
\ No newline at end of file
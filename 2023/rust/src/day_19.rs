@@ -56,7 +56,6 @@ impl Part {
     }
 
     fn from_str(text: &str) -> Self {
-        println!("Parsing Part from {text:?}");
         let values: Vec<i64> = text
         .strip_prefix("{").unwrap()
         .strip_suffix("}").unwrap()
@@ -147,14 +146,11 @@ impl Rule {
         .collect::<Vec<&Decider>>();
 
         if matched_deciders.is_empty() {
-            print!("(fall)");
             return self.fallback_destination.clone()
         }
         let _first = matched_deciders
         .first()
         .unwrap();
-        let _first_subject = _first.subject;
-        print!("({_first_subject:?})");
         _first.destination
         .clone()
     }
@@ -170,17 +166,14 @@ fn parse_input(text: &str) -> (Vec<Rule>, Vec<Part>) {
 
 fn is_accepted(part: &Part, rules: &HashMap<String, &Rule>) -> bool {
     let in_rule = rules.get("in").unwrap();
-    println!("Accepting {part:?}");
 
     let mut destination = in_rule.apply(part);
     
     let terminal_destinations = ["A", "R"];
 
     while ! terminal_destinations.contains(&destination.as_str()) {
-        print!("{destination:?} -> ");
         destination = rules.get(&destination).unwrap().apply(part);
     }
-    println!("{destination:?}");
     match destination.as_str() {
         "A" => true,
         "R" => false,
@@ -189,6 +182,8 @@ fn is_accepted(part: &Part, rules: &HashMap<String, &Rule>) -> bool {
 }
 
 
+aoc_commons::solution!(2023, 19, first_part, second_part);
+
 pub fn first_part(input: &str) -> i64 {
     let (rules, parts) = parse_input(input);
     let rules_map: HashMap<String, &Rule> = rules
@@ -196,8 +191,6 @@ pub fn first_part(input: &str) -> i64 {
     .map(|r| (r.name.clone(), r))
     .collect();
 
-    println!("{rules_map:?}");
-
     let accepted_parts: Vec<&Part> = parts
     .iter()
     .filter(|p| is_accepted(p, &rules_map))
@@ -280,11 +273,12 @@ pub fn second_part(input: &str) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::{example, real};
     use crate::day_19::{first_part, second_part, parse_input};
 
     #[test]
     fn test_parsing() {
-        let (rules, parts) = parse_input(include_str!("inputs/19_example_1.txt"));
+        let (rules, parts) = parse_input(&example(2023, 19, 1));
 
         assert_eq!(rules.len(), 11);
         assert_eq!(parts.len(), 5);
@@ -292,14 +286,14 @@ mod tests {
 
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/19_example_1.txt")), 19114);
-        assert_eq!(second_part(include_str!("inputs/19_example_1.txt")), 167409079868000i64);
+        assert_eq!(first_part(&example(2023, 19, 1)), 19114);
+        assert_eq!(second_part(&example(2023, 19, 1)), 167409079868000i64);
     }
     
     #[test]
     fn test_parts() {
         // unimplemented!();
-        assert_eq!(first_part(include_str!("inputs/19.secret")), 472630);
-        assert_eq!(second_part(include_str!("inputs/19.secret")), 116738260946855);
+        assert_eq!(first_part(&real(2023, 19)), 472630);
+        assert_eq!(second_part(&real(2023, 19)), 116738260946855);
     }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use std::{collections::{HashMap, VecDeque}, default, iter::FlatMap};
 
+use aoc_commons::error::{parse_lines, SolveError};
 use itertools::Itertools;
 
 
@@ -31,8 +32,8 @@ enum ModuleState {
     None
 }
 
-fn parse_line(line: &str) -> (String, Module) {
-    let (name, outputs) = line.split_once("->").unwrap();
+fn parse_line(line: &str) -> Result<(String, Module), SolveError> {
+    let (name, outputs) = line.split_once("->").ok_or(SolveError::new("missing '->'"))?;
 
     let (actual_name, kind): (String, ModuleKind) = {
         if name.starts_with("b") {
@@ -42,20 +43,17 @@ fn parse_line(line: &str) -> (String, Module) {
         } else if name.starts_with("&") {
             (name[1..name.len()].trim().to_string(), ModuleKind::Conjunction)
         } else {
-            dbg!(name);
-            panic!("Unexpected name {name:?}");
+            return Err(SolveError::invalid_token(line, name.trim(), "module name"));
         }
     };
 
     let outputs = outputs.split(",").map(|x| x.trim().to_string()).collect();
     
-    (actual_name.clone(), Module{name: actual_name.clone(), kind: kind, outputs: outputs})
+    Ok((actual_name.clone(), Module{name: actual_name.clone(), kind: kind, outputs: outputs}))
 }
 
-fn parse(text: &str) -> HashMap<String, Module> {
-    text.split("\n")
-    .filter(|x| !x.is_empty())
-    .map(parse_line).collect()
+fn parse(text: &str) -> Result<HashMap<String, Module>, SolveError> {
+    Ok(parse_lines(text, parse_line)?.into_iter().collect())
 }
 
 fn simulate_broadcast(module: &Module, pulse: Pulse, pulse_queue: &mut VecDeque<(String, Pulse, String)>, states: &mut HashMap<String, ModuleState>) {
@@ -133,10 +131,6 @@ fn simulate(modules: &HashMap<String, Module>, states: &mut HashMap<String, Modu
 
     while !pulse_queue.is_empty() {
         let (last_name, current_pulse, current_name) = pulse_queue.pop_back().unwrap();
-        let x = ["qt".to_string(), "rx".to_string(), "mr".to_string(), "kk".to_string(), "bb".to_string(), "gl".to_string()];
-        if x.contains(&last_name) || x.contains(&current_name) {
-            println!("{last_name:?} -{current_pulse:?} {current_name:?}");
-        }
         saved_pulses.push((current_pulse, current_name.clone()));
 
         match modules.get(&current_name) {
@@ -181,8 +175,11 @@ fn initialize_states(modules: &HashMap<String, Module>) -> HashMap<String, Modul
     ).collect()
 }
 
-pub fn first_part(input: &str) -> i32 {
-    let modules = parse(input);
+// pressing the button until `rx` gets a low pulse does not finish on the real input yet
+aoc_commons::solution!(2023, 20, first_part, second_part, skip = [2]);
+
+pub fn first_part(input: &str) -> Result<i32, SolveError> {
+    let modules = parse(input)?;
     
     let mut states = initialize_states(&modules);
     let mut pulses = Vec::new();
@@ -191,11 +188,11 @@ pub fn first_part(input: &str) -> i32 {
     }
     let n_low_pulses = pulses.iter().flatten().filter(|(p, d)| matches!(p, Pulse::Low)).count();
     let n_hight_pulses = pulses.iter().flatten().filter(|(p, d)| matches!(p, Pulse::High)).count();
-    (n_low_pulses * n_hight_pulses) as i32
+    Ok((n_low_pulses * n_hight_pulses) as i32)
 }
 
-pub fn second_part(input: &str) -> i64 {
-    let modules = parse(input);
+pub fn second_part(input: &str) -> Result<i64, SolveError> {
+    let modules = parse(input)?;
     
     let mut states = initialize_states(&modules);
     let mut n_presses = 0i64;
@@ -204,26 +201,33 @@ pub fn second_part(input: &str) -> i64 {
         let pulses = simulate(&modules, &mut states);
         let res = pulses.iter().find(|(p, d)| (p, d) == (&Pulse::Low, &"rx".to_string()));
         if matches!(res, Some(x)) {
-            return n_presses
-        } else {
-            // println!("{n_presses}")
+            return Ok(n_presses)
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day_20::{first_part, second_part};
+    use aoc_commons::inputs::{example, real};
+    use crate::day_20::{first_part, second_part, parse};
     
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/20_example_1.txt")), 32000000);
-        assert_eq!(first_part(include_str!("inputs/20_example_2.txt")), 11687500);
+        assert_eq!(first_part(&example(2023, 20, 1)), Ok(32000000));
+        assert_eq!(first_part(&example(2023, 20, 2)), Ok(11687500));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("broadcaster -> a\n%a -> b\n#b -> a\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: invalid module name '#b'");
+        let error = parse("broadcaster a").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: missing '->'");
     }
     
     #[test]
     fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/20.secret")), 879834312);
-        // assert_eq!(second_part(include_str!("inputs/20.secret")), 0);
+        assert_eq!(first_part(&real(2023, 20)), Ok(879834312));
+        // assert_eq!(second_part(&real(2023, 20)), Ok(0));
     }
 }
\ No newline at end of file
@@ -1,5 +1,3 @@
-mod utils;
-
 mod day_10;
 mod day_09;
 mod day_07;
@@ -14,41 +12,26 @@ mod day_18;
 mod day_19;
 mod day_20;
 
+use aoc_commons::{cli, Solution};
+
+const YEAR: u16 = 2023;
+
+const SOLUTIONS: &[&dyn Solution] = &[
+    &day_01::Solver,
+    &day_02::Solver,
+    &day_06::Solver,
+    &day_07::Solver,
+    &day_09::Solver,
+    &day_10::Solver,
+    &day_14::Solver,
+    &day_15::Solver,
+    &day_16::Solver,
+    &day_17::Solver,
+    &day_18::Solver,
+    &day_19::Solver,
+    &day_20::Solver,
+];
+
 fn main() {
-    // let solution = day_01::first_part();
-    // let solution = day_01::second_part();
-    // let solution = day_02::first_part(include_str!("inputs/02.secret"));
-    // let solution = day_02::second_part(include_str!("inputs/02.secret"));
-    // let solution = day_06::first_part(include_str!("inputs/06.secret"));
-    // let solution = day_06::second_part(include_str!("inputs/06.secret"));
-    // println!("{solution}");
-    // let solution = day_07::first_part(include_str!("inputs/07.secret"));
-    // println!("{solution}");
-    // let solution = day_07::second_part(include_str!("inputs/07.secret"));
-    // println!("{solution}");
-    // let solution = day_09::first_part(include_str!("inputs/09.secret"));
-    // println!("{solution}");
-    // let solution = day_09::second_part(include_str!("inputs/09.secret"));
-    // println!("{solution}");
-    // let solution = day_10::first_part(include_str!("inputs/10.secret"));
-    // let solution = day_10::second_part(include_str!("inputs/10.secret"));
-    // println!("{solution}");
-    // let solution = day_14::first_part(include_str!("inputs/14.secret"));
-    let solution = day_14::second_part(include_str!("inputs/14.secret"));
-    println!("{solution}");
-    // let solution = day_15::first_part(include_str!("inputs/15.secret"));
-    // let solution = day_15::second_part(include_str!("inputs/15.secret"));
-    // println!("{solution}");
-    // let solution = day_16::first_part(include_str!("inputs/16.secret"));
-    // let solution = day_16::second_part(include_str!("inputs/16.secret"));
-    // let solution = day_17::first_part(include_str!("inputs/17.secret"));
-    // println!("{solution}");
-    // let solution = day_18::first_part(include_str!("inputs/18.secret"));
-    // println!("{solution}");
-    // let solution = day_19::first_part(include_str!("inputs/19.secret"));
-    // let solution = day_19::second_part(include_str!("inputs/19.secret"))
-    // println!("{solution}");
-    // let solution = day_20::first_part(include_str!("inputs/20.secret"));
-    // let solution = day_20::second_part(include_str!("inputs/20.secret"));
-    // println!("{solution}");
+    cli::main(YEAR, SOLUTIONS);
 }
@@ -1,24 +1,24 @@
+use aoc_commons::error::SolveError;
 
-pub fn first_part(input: &str) -> i32 {
-    return -1;
+aoc_commons::solution!(2023, DAY_NUMBER, first_part, second_part);
+
+
+pub fn first_part(_input: &str) -> Result<i32, SolveError> {
+    Err(SolveError::new("not solved yet"))
 }
 
-pub fn second_part(input: &str) -> i32 {
-    return -1;
+pub fn second_part(_input: &str) -> Result<i32, SolveError> {
+    Err(SolveError::new("not solved yet"))
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_commons::inputs::example;
     use crate::day_DAY::{first_part, second_part};
     
     #[test]
     fn test_example() {
-        assert_eq!(first_part(include_str!("inputs/DAY_example_1.txt")), 0);
-    }
-    
-    #[test]
-    fn test_parts() {
-        assert_eq!(first_part(include_str!("inputs/DAY.secret")), 0);
-        assert_eq!(second_part(include_str!("inputs/DAY.secret")), 0);
+        assert_eq!(first_part(&example(2023, DAY_NUMBER, 1)), Ok(0));
+        assert_eq!(second_part(&example(2023, DAY_NUMBER, 1)), Ok(0));
     }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 const DIAL_SIZE: i32 = 100;
 const START: i32 = 50;
 
+aoc_commons::solution!(2025, 1, p1, p2);
+
 pub fn p1(input: &str) -> i32 {
     let directions = parse_directions(input);
     let mut positions = vec![50];
@@ -48,18 +50,18 @@ fn parse_directions(input: &str) -> Vec<i32> {
 }
 
 mod tests {
-    use std::fs;
 
     use crate::day_01::{p1, p2};
+    use aoc_commons::inputs::example;
 
     #[test]
     fn test_p1() {
-        let input = fs::read_to_string("inputs/01.example").unwrap();
+        let input = example(2025, 1, 1);
         assert_eq!(p1(&input), 3);
     }
     #[test]
     fn test_p2() {
-        let input = fs::read_to_string("inputs/01.example").unwrap();
+        let input = example(2025, 1, 1);
         assert_eq!(p2(&input), 6);
     }
 }
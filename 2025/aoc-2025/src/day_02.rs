@@ -1,3 +1,5 @@
+aoc_commons::solution!(2025, 2, p1, p2);
+
 pub fn p1(input: &str) -> i64 {
     input
         .split(",")
@@ -64,18 +66,18 @@ fn is_invalid(x_str: &String, size: usize) -> bool {
 }
 
 mod tests {
-    use std::fs;
 
     use crate::day_02::{is_invalid_any, is_invalid_half, p1, p2};
+    use aoc_commons::inputs::example;
 
     #[test]
     fn test_p1() {
-        let input_02 = fs::read_to_string("inputs/02.example").unwrap();
+        let input_02 = example(2025, 2, 1);
         assert_eq!(p1(input_02.as_str()), 1227775554);
     }
     #[test]
     fn test_p2() {
-        let input_02 = fs::read_to_string("inputs/02.example").unwrap();
+        let input_02 = example(2025, 2, 1);
         assert_eq!(p2(input_02.as_str()), 4174379265);
     }
 
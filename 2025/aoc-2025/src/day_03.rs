@@ -1,5 +1,7 @@
 const RADIX: i64 = 10;
 
+aoc_commons::solution!(2025, 3, p1, p2);
+
 pub fn p1(input: &str) -> i64 {
     input
         .split("\n")
@@ -51,13 +53,13 @@ fn highest_joltage_general(x: &str, n: usize) -> i64 {
 }
 
 mod tests {
-    use std::fs;
 
     use crate::day_03::{highest_joltage_general, p1, p2};
+    use aoc_commons::inputs::example;
 
     #[test]
     fn test_p1() {
-        let input_03 = fs::read_to_string("inputs/03.example").unwrap();
+        let input_03 = example(2025, 3, 1);
         assert_eq!(p1(&input_03), 357);
     }
 
@@ -69,7 +71,7 @@ mod tests {
     }
     #[test]
     fn test_p2() {
-        let input_03 = fs::read_to_string("inputs/03.example").unwrap();
+        let input_03 = example(2025, 3, 1);
         assert_eq!(p2(&input_03), 3121910778619);
     }
 }
@@ -1,96 +1,71 @@
-use crate::commons::Vec2;
+use aoc_commons::error::SolveError;
+use aoc_commons::grid::{Grid, Point};
 
-pub fn p1(input: &str) -> i32 {
-    let grid = parse(input);
-    let dim = Vec2::new(grid.first().unwrap().len() as i32, grid.len() as i32);
+aoc_commons::solution!(2025, 4, p1, p2);
 
-    find_accessible_papers(&grid, &dim).len() as i32
-}
-
-fn find_accessible_papers(grid: &Vec<Vec<char>>, dim: &Vec2<i32>) -> Vec<Vec2<i32>> {
-    let mut accessible = vec![];
-    for y in 0..dim.y {
-        for x in 0..dim.x {
-            let p = Vec2::new(x, y);
-            if grid[p.y as usize][p.x as usize] == '@' {
-                let adjacents = eight_adjacents(&p, &dim);
+pub fn p1(input: &str) -> Result<i32, SolveError> {
+    let grid = parse(input)?;
 
-                let n_papers_around = adjacents
-                    .iter()
-                    .filter(|a| grid[a.y as usize][a.x as usize] == '@')
-                    .count();
+    Ok(find_accessible_papers(&grid).len() as i32)
+}
 
-                if n_papers_around < 4 {
-                    accessible.push(p);
-                }
-            }
-        }
-    }
-    accessible
+fn find_accessible_papers(grid: &Grid<char>) -> Vec<Point> {
+    grid.iter()
+        .filter(|(_, c)| **c == '@')
+        .filter(|(p, _)| {
+            let n_papers_around = grid
+                .eight_neighborhood_at(p)
+                .iter()
+                .filter(|(_, c)| **c == '@')
+                .count();
+            n_papers_around < 4
+        })
+        .map(|(p, _)| p)
+        .collect()
 }
 
-pub fn p2(input: &str) -> i32 {
-    let mut grid = parse(input);
-    let dim = Vec2::new(grid.first().unwrap().len() as i32, grid.len() as i32);
+pub fn p2(input: &str) -> Result<i32, SolveError> {
+    let mut grid = parse(input)?;
 
     let mut n_accessible = 0;
     loop {
-        let accessible = find_accessible_papers(&grid, &dim);
+        let accessible = find_accessible_papers(&grid);
 
         if accessible.is_empty() {
             break;
         }
 
         for a in accessible.iter() {
-            grid[a.y as usize][a.x as usize] = '.';
+            *grid.at_mut(a).unwrap() = '.';
         }
 
         n_accessible += accessible.len();
     }
-    n_accessible as i32
-}
-
-fn parse(input: &str) -> Vec<Vec<char>> {
-    input
-        .split("\n")
-        .filter(|x| x.trim().len() > 0)
-        .map(|line| line.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>()
+    Ok(n_accessible as i32)
 }
 
-fn eight_adjacents(p: &Vec2<i32>, dim: &Vec2<i32>) -> Vec<Vec2<i32>> {
-    let (w, h) = (dim.x, dim.y);
-
-    [
-        Vec2::new(p.x - 1, p.y + 0),
-        Vec2::new(p.x - 1, p.y + 1),
-        Vec2::new(p.x - 1, p.y - 1),
-        Vec2::new(p.x + 1, p.y + 0),
-        Vec2::new(p.x + 1, p.y + 1),
-        Vec2::new(p.x + 1, p.y - 1),
-        Vec2::new(p.x + 0, p.y - 1),
-        Vec2::new(p.x + 0, p.y + 1),
-    ]
-    .into_iter()
-    .filter(|s| s.x >= 0 && s.x < w && s.y >= 0 && s.y < h)
-    .collect::<Vec<_>>()
+fn parse(input: &str) -> Result<Grid<char>, SolveError> {
+    Grid::parse_chars(input, |c| match c {
+        '@' | '.' => Ok(c),
+        _ => Err(format!("unexpected cell '{}'", c)),
+    })
 }
 
 mod tests {
     use crate::day_04::{p1, p2};
-    use std::fs;
+    use aoc_commons::inputs::{custom, example};
 
     #[test]
     fn test_p1() {
-        let input = fs::read_to_string("inputs/04.custom").unwrap();
-        assert_eq!(p1(&input), 2);
-        let input = fs::read_to_string("inputs/04.example").unwrap();
-        assert_eq!(p1(&input), 13);
+        let input = custom(2025, 4, "custom");
+        assert_eq!(p1(&input), Ok(2));
+        let input = example(2025, 4, 1);
+        assert_eq!(p1(&input), Ok(13));
     }
 
     #[test]
     fn test_p2() {
-        let input = fs::read_to_string("inputs/04.example").unwrap();
-        assert_eq!(p2(&input), 43);
+        let input = example(2025, 4, 1);
+        assert_eq!(p2(&input), Ok(43));
     }
 }
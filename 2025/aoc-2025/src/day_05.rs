@@ -1,5 +1,7 @@
 use std::{ops::RangeInclusive, usize};
 
+aoc_commons::solution!(2025, 5, p1, p2);
+
 pub fn p1(input: &str) -> i32 {
     let (ranges, fruits) = parse(input);
 
@@ -69,18 +71,18 @@ fn parse_range(input: &str) -> RangeInclusive<usize> {
 }
 
 mod tests {
-    use std::fs;
 
     use crate::day_05::{p1, p2};
+    use aoc_commons::inputs::example;
 
     #[test]
     fn test_p1() {
-        let input = fs::read_to_string("inputs/05.example").unwrap();
+        let input = example(2025, 5, 1);
         assert_eq!(p1(&input), 3);
     }
     #[test]
     fn test_p2() {
-        let input = fs::read_to_string("inputs/05.example").unwrap();
+        let input = example(2025, 5, 1);
         assert_eq!(p2(&input), 14);
     }
 }
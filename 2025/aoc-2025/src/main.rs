@@ -1,6 +1,5 @@
-use std::fs;
+use aoc_commons::{Solution, cli};
 
-mod commons;
 mod day_01;
 mod day_02;
 mod day_03;
@@ -8,17 +7,16 @@ mod day_04;
 mod day_05;
 mod day_06;
 
+const YEAR: u16 = 2025;
+const SOLUTIONS: &[&dyn Solution] = &[
+    &day_01::Solver,
+    &day_02::Solver,
+    &day_03::Solver,
+    &day_04::Solver,
+    &day_05::Solver,
+    &day_06::Solver,
+];
+
 fn main() {
-    // let input_01 = fs::read_to_string("inputs/01").unwrap();
-    // println!("{}", day_01::solve_part2(&input_01))
-    // let input_02 = fs::read_to_string("inputs/02").unwrap();
-    // println!("{}", day_02::solve_part_two(&input_02))
-    // let input_03 = fs::read_to_string("inputs/03.in").unwrap();
-    // println!("{}", day_03::solve_part_two(&input_03))
-    // let input = fs::read_to_string("inputs/04.in").unwrap();
-    // println!("{}", day_04::p2(&input))
-    // let input = fs::read_to_string("inputs/05.in").unwrap();
-    // println!("{}", day_05::p2(&input))
-    let input = fs::read_to_string("inputs/06.in").unwrap();
-    println!("{}", day_06::p2(&input))
+    cli::main(YEAR, SOLUTIONS);
 }
@@ -0,0 +1,29 @@
+use aoc_commons::error::SolveError;
+
+aoc_commons::solution!(2025, DAY_NUMBER, p1, p2);
+
+pub fn p1(_input: &str) -> Result<i64, SolveError> {
+    Err(SolveError::new("not solved yet"))
+}
+
+pub fn p2(_input: &str) -> Result<i64, SolveError> {
+    Err(SolveError::new("not solved yet"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_DAY::{p1, p2};
+    use aoc_commons::inputs::example;
+
+    #[test]
+    fn test_p1() {
+        let input = example(2025, DAY_NUMBER, 1);
+        assert_eq!(p1(&input), Ok(0));
+    }
+
+    #[test]
+    fn test_p2() {
+        let input = example(2025, DAY_NUMBER, 1);
+        assert_eq!(p2(&input), Ok(0));
+    }
+}
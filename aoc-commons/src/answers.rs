@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::Answer;
+
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.json";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Expected answers of one year keyed by (day, part).
+///
+/// The manifest is a JSON list of `{"day": 6, "part": 1, "expected": 387413}`
+/// entries; `expected` may be a number or a string.
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn from_json(raw: &str) -> Result<Answers, String> {
+        let value: Value = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+        let entries = value.as_array().ok_or("expected a list of answers")?;
+
+        let mut expected = HashMap::new();
+        for entry in entries {
+            let int = |key: &str| {
+                entry[key]
+                    .as_u64()
+                    .and_then(|v| u8::try_from(v).ok())
+                    .ok_or(format!("missing '{}' in {}", key, entry))
+            };
+            let answer = match &entry["expected"] {
+                Value::String(s) => s.clone(),
+                Value::Number(n) => n.to_string(),
+                _ => return Err(format!("missing 'expected' in {}", entry)),
+            };
+            expected.insert((int("day")?, int("part")?), answer);
+        }
+        Ok(Answers { expected })
+    }
+
+    /// Loads the manifest; a missing file means that no answer is known yet.
+    pub fn load(path: &str) -> Result<Answers, String> {
+        match std::fs::read_to_string(path) {
+            Ok(raw) => Answers::from_json(&raw).map_err(|e| format!("{}: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {}", path, e)),
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.expected.get(&(day, part)) {
+            None => Verdict::Unknown,
+            Some(expected) if *expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_answers {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::from_json(
+            r#"[
+                {"day": 6, "part": 1, "expected": 387413},
+                {"day": 6, "part": 2, "expected": "1738377086345"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.check(6, 1, &Answer::from(387413)), Verdict::Pass);
+        assert_eq!(
+            answers.check(6, 2, &Answer::from(1738377086345_i64)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(6, 1, &Answer::from(5934)),
+            Verdict::Fail {
+                expected: "387413".to_string()
+            }
+        );
+        assert_eq!(answers.check(7, 1, &Answer::from(1)), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_invalid_manifest() {
+        assert!(Answers::from_json("{}").is_err());
+        assert!(Answers::from_json(r#"[{"day": 6, "part": 1}]"#).is_err());
+        assert!(Answers::from_json(r#"[{"day": 600, "part": 1, "expected": 1}]"#).is_err());
+    }
+
+    #[test]
+    fn test_missing_manifest() {
+        let answers = Answers::load("does/not/exist.json").unwrap();
+        assert_eq!(answers.check(1, 1, &Answer::from(1)), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,421 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use serde_json::{json, Value};
+
+use crate::inputs::{load, Variant};
+use crate::solution::{selected_parts, solve_part, Solution};
+
+const W_DAY: usize = 8;
+const W_PART: usize = 6;
+const W_DURATION: usize = 12;
+const W_SAMPLES: usize = 14;
+
+const WARMUP_SECS: f64 = 0.2;
+const TARGET_SECS: f64 = 1.0;
+const MIN_SAMPLES: usize = 3;
+const MAX_SAMPLES: usize = 10_000;
+const OUTLIER_IQR_FACTOR: f64 = 1.5;
+
+const CSV_HEADER: &str = "year,day,part,median_s,mean_s,min_s,max_s,stddev_s,samples,outliers";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+/// Summary of the run times (in seconds) that survived outlier rejection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub median: f64,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    pub stddev: f64,
+    pub n_samples: usize,
+    pub n_outliers: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[f64]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let kept = reject_outliers(&sorted);
+        let mean = mean(&kept);
+        let variance = kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / kept.len() as f64;
+
+        Stats {
+            median: quantile(&kept, 0.5),
+            mean,
+            min: kept[0],
+            max: kept[kept.len() - 1],
+            stddev: variance.sqrt(),
+            n_samples: kept.len(),
+            n_outliers: sorted.len() - kept.len(),
+        }
+    }
+}
+
+fn mean(numbers: &[f64]) -> f64 {
+    numbers.iter().sum::<f64>() / numbers.len() as f64
+}
+
+/// Linearly interpolated quantile of already sorted numbers.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// Drops samples outside of the Tukey fences `[Q1 - k * IQR, Q3 + k * IQR]`.
+fn reject_outliers(sorted: &[f64]) -> Vec<f64> {
+    let q1 = quantile(sorted, 0.25);
+    let q3 = quantile(sorted, 0.75);
+    let fence = OUTLIER_IQR_FACTOR * (q3 - q1);
+
+    sorted
+        .iter()
+        .cloned()
+        .filter(|&x| x >= q1 - fence && x <= q3 + fence)
+        .collect()
+}
+
+fn measure_run<S: ?Sized, T, F: Fn(&S) -> T>(f: &F, input: &S) -> f64 {
+    let start = Instant::now();
+    black_box(f(black_box(input)));
+    start.elapsed().as_secs_f64()
+}
+
+pub fn benchmark_run<S: ?Sized, T, F: Fn(&S) -> T>(f: F, input: &S) -> Stats {
+    let warmup_start = Instant::now();
+    let mut warmup_runs = vec![];
+    while warmup_runs.is_empty() || warmup_start.elapsed().as_secs_f64() < WARMUP_SECS {
+        warmup_runs.push(measure_run(&f, input));
+    }
+
+    let estimate = mean(&warmup_runs).max(f64::EPSILON);
+    let n = ((TARGET_SECS / estimate) as usize).clamp(MIN_SAMPLES, MAX_SAMPLES);
+
+    let run_times = (0..n).map(|_| measure_run(&f, input)).collect::<Vec<_>>();
+    Stats::from_samples(&run_times)
+}
+
+pub fn format_duration(secs: f64) -> String {
+    let (value, unit) = if secs < 1e-6 {
+        (secs * 1e9, "ns")
+    } else if secs < 1e-3 {
+        (secs * 1e6, "µs")
+    } else if secs < 1. {
+        (secs * 1e3, "ms")
+    } else {
+        (secs, "s")
+    };
+    format!("{:.3} {}", value, unit)
+}
+
+pub fn print_header() {
+    print!("{:<w$}", "day", w = W_DAY);
+    print!("{:<w$}", "part", w = W_PART);
+    for column in ["median", "min", "max", "stddev"] {
+        print!("{:>w$}", column, w = W_DURATION);
+    }
+    print!("{:>w$}", "samples", w = W_SAMPLES);
+    println!();
+    println!(
+        "{:-<w$}",
+        "",
+        w = W_DAY + W_PART + W_DURATION * 4 + W_SAMPLES
+    );
+}
+
+pub fn print_day(day: u8, part: u8, stats: &Stats) {
+    print!("{:<w$}", format!("day {:02}", day), w = W_DAY);
+    print!("{:<w$}", part, w = W_PART);
+    for value in [stats.median, stats.min, stats.max, stats.stddev] {
+        print!("{:>w$}", format_duration(value), w = W_DURATION);
+    }
+    let samples = if stats.n_outliers > 0 {
+        format!("{} (-{})", stats.n_samples, stats.n_outliers)
+    } else {
+        stats.n_samples.to_string()
+    };
+    println!("{:>w$}", samples, w = W_SAMPLES);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub stats: Stats,
+}
+
+impl BenchmarkResult {
+    fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "median_s": self.stats.median,
+            "mean_s": self.stats.mean,
+            "min_s": self.stats.min,
+            "max_s": self.stats.max,
+            "stddev_s": self.stats.stddev,
+            "samples": self.stats.n_samples,
+            "outliers": self.stats.n_outliers,
+        })
+    }
+
+    fn from_json(value: &Value) -> Result<BenchmarkResult, String> {
+        let int = |key: &str| {
+            value[key]
+                .as_u64()
+                .ok_or(format!("missing integer '{}' in {}", key, value))
+        };
+        let float = |key: &str| {
+            value[key]
+                .as_f64()
+                .ok_or(format!("missing number '{}' in {}", key, value))
+        };
+
+        Ok(BenchmarkResult {
+            year: int("year")? as u16,
+            day: int("day")? as u8,
+            part: int("part")? as u8,
+            stats: Stats {
+                median: float("median_s")?,
+                mean: float("mean_s")?,
+                min: float("min_s")?,
+                max: float("max_s")?,
+                stddev: float("stddev_s")?,
+                n_samples: int("samples")? as usize,
+                n_outliers: int("outliers")? as usize,
+            },
+        })
+    }
+
+    fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{:e},{:e},{:e},{:e},{:e},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            self.stats.median,
+            self.stats.mean,
+            self.stats.min,
+            self.stats.max,
+            self.stats.stddev,
+            self.stats.n_samples,
+            self.stats.n_outliers
+        )
+    }
+}
+
+pub fn results_to_json(results: &[BenchmarkResult]) -> String {
+    let values = results.iter().map(|r| r.to_json()).collect::<Vec<_>>();
+    serde_json::to_string_pretty(&values).unwrap()
+}
+
+pub fn results_from_json(raw: &str) -> Result<Vec<BenchmarkResult>, String> {
+    let value: Value = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+    value
+        .as_array()
+        .ok_or("expected a list of benchmark results")?
+        .iter()
+        .map(BenchmarkResult::from_json)
+        .collect()
+}
+
+pub fn results_to_csv(results: &[BenchmarkResult]) -> String {
+    let mut lines = vec![CSV_HEADER.to_string()];
+    lines.extend(results.iter().map(|r| r.to_csv_row()));
+    lines.join("\n")
+}
+
+/// Benchmarks both parts of every solution on its real input; with `all` set,
+/// the parts a solution skips are left out.
+pub fn benchmark_all(
+    solutions: &[&dyn Solution],
+    format: OutputFormat,
+    all: bool,
+) -> Vec<BenchmarkResult> {
+    if format == OutputFormat::Table {
+        print_header();
+    }
+
+    let mut results = vec![];
+    for solution in solutions {
+        let raw_input = match load(solution.year(), solution.day(), &Variant::Real) {
+            Ok(raw_input) => raw_input,
+            Err(e) => {
+                eprintln!("day {:02}: {}", solution.day(), e);
+                continue;
+            }
+        };
+
+        for part in selected_parts(*solution, all) {
+            if let Err(e) = solve_part(*solution, part, &raw_input) {
+                eprintln!("day {:02} part {}: {}", solution.day(), part, e);
+                continue;
+            }
+            let stats = benchmark_run(|i| solution.part(part, i), raw_input.as_str());
+            if format == OutputFormat::Table {
+                print_day(solution.day(), part, &stats);
+            }
+            results.push(BenchmarkResult {
+                year: solution.year(),
+                day: solution.day(),
+                part,
+                stats,
+            });
+        }
+    }
+
+    match format {
+        OutputFormat::Table => (),
+        OutputFormat::Json => println!("{}", results_to_json(&results)),
+        OutputFormat::Csv => println!("{}", results_to_csv(&results)),
+    }
+    results
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: f64,
+    pub current: f64,
+    pub regressed: bool,
+}
+
+/// Pairs current results with the baseline by (year, day, part) and flags
+/// those whose median grew by more than `threshold` (relative, e.g. `0.1`).
+pub fn compare(
+    baseline: &[BenchmarkResult],
+    current: &[BenchmarkResult],
+    threshold: f64,
+) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|c| {
+            let b = baseline
+                .iter()
+                .find(|b| (b.year, b.day, b.part) == (c.year, c.day, c.part))?;
+            Some(Comparison {
+                day: c.day,
+                part: c.part,
+                baseline: b.stats.median,
+                current: c.stats.median,
+                regressed: c.stats.median > b.stats.median * (1. + threshold),
+            })
+        })
+        .collect()
+}
+
+pub fn print_comparison(comparisons: &[Comparison]) {
+    print!("{:<w$}", "day", w = W_DAY);
+    print!("{:<w$}", "part", w = W_PART);
+    for column in ["baseline", "current", "change"] {
+        print!("{:>w$}", column, w = W_DURATION);
+    }
+    println!();
+    println!(
+        "{:-<w$}",
+        "",
+        w = W_DAY + W_PART + W_DURATION * 3 + W_SAMPLES
+    );
+
+    for c in comparisons {
+        print!("{:<w$}", format!("day {:02}", c.day), w = W_DAY);
+        print!("{:<w$}", c.part, w = W_PART);
+        print!("{:>w$}", format_duration(c.baseline), w = W_DURATION);
+        print!("{:>w$}", format_duration(c.current), w = W_DURATION);
+        let change = (c.current / c.baseline - 1.) * 100.;
+        print!("{:>w$}", format!("{:+.1} %", change), w = W_DURATION);
+        println!(
+            "{:>w$}",
+            if c.regressed { "REGRESSED" } else { "" },
+            w = W_SAMPLES
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_benchmark {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[3., 1., 2., 5., 4.]);
+        assert_eq!(stats.median, 3.);
+        assert_eq!(stats.mean, 3.);
+        assert_eq!((stats.min, stats.max), (1., 5.));
+        assert_eq!(stats.stddev, 2_f64.sqrt());
+        assert_eq!((stats.n_samples, stats.n_outliers), (5, 0));
+    }
+
+    #[test]
+    fn test_outlier_rejection() {
+        let stats = Stats::from_samples(&[1.0, 1.1, 0.9, 1.0, 1.05, 0.95, 25.0]);
+        assert_eq!(stats.n_outliers, 1);
+        assert_eq!(stats.max, 1.1);
+        assert_eq!(stats.median, 1.0);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0.000_000_016), "16.000 ns");
+        assert_eq!(format_duration(0.000_052), "52.000 µs");
+        assert_eq!(format_duration(0.001_390), "1.390 ms");
+        assert_eq!(format_duration(6.936), "6.936 s");
+        assert_eq!(format_duration(120.5), "120.500 s");
+    }
+
+    #[test]
+    fn test_benchmark_run() {
+        let stats = benchmark_run(|s: &str| s.len(), "input");
+        assert!(stats.n_samples >= MIN_SAMPLES);
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+    }
+
+    fn result(day: u8, part: u8, median: f64) -> BenchmarkResult {
+        BenchmarkResult {
+            year: 2021,
+            day,
+            part,
+            stats: Stats::from_samples(&[median]),
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let results = vec![result(4, 1, 0.00139), result(4, 2, 6.936)];
+        let raw = results_to_json(&results);
+        assert_eq!(results_from_json(&raw), Ok(results));
+        assert!(results_from_json("{}").is_err());
+        assert!(results_from_json("[{\"day\": 4}]").is_err());
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = results_to_csv(&[result(4, 1, 0.5)]);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "2021,4,1,5e-1,5e-1,5e-1,5e-1,0e0,1,0");
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = vec![result(4, 1, 1.0), result(4, 2, 1.0), result(5, 1, 1.0)];
+        let current = vec![result(4, 1, 1.05), result(4, 2, 1.2), result(6, 1, 9.0)];
+
+        let comparisons = compare(&baseline, &current, 0.1);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert_eq!((comparisons[1].day, comparisons[1].part), (4, 2));
+    }
+}
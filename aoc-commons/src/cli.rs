@@ -0,0 +1,481 @@
+use std::path::PathBuf;
+
+use crate::answers::{Answers, Verdict, DEFAULT_ANSWERS_PATH};
+use crate::benchmark::{
+    benchmark_all, compare, print_comparison, results_from_json, results_to_json, OutputFormat,
+};
+use crate::inputs::{crate_dir, load, Variant};
+use crate::scaffold::scaffold_day;
+use crate::solution::{find_solution, selected_parts, solve_part, Solution};
+
+pub const USAGE: &str = "usage:
+    <bin> run <day> [--part <1|2>] [--input <path> | --example <n>]
+    <bin> bench (--all | <day>...) [--format <table|json|csv>] [--save <path>]
+                [--compare <path>] [--threshold <percent>]
+    <bin> check (--all | <day>...) [--answers <path>]
+    <bin> new <day>";
+
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10.;
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub days: Option<Vec<u8>>,
+    pub format: OutputFormat,
+    pub save: Option<String>,
+    pub compare: Option<String>,
+    pub threshold: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        input: Variant,
+    },
+    Bench(BenchOptions),
+    Check {
+        days: Option<Vec<u8>>,
+        answers: Option<String>,
+    },
+    New {
+        day: u8,
+    },
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let (command, rest) = args.split_first().ok_or("missing command")?;
+
+    match command.as_str() {
+        "run" => parse_run(rest),
+        "bench" => parse_bench(rest),
+        "check" => parse_check(rest),
+        "new" => match rest {
+            [day] => Ok(Command::New {
+                day: parse_day(day)?,
+            }),
+            _ => Err("expected exactly one day".to_string()),
+        },
+        other => Err(format!("unknown command '{}'", other)),
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = Variant::Real;
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let value = args_iter.next().ok_or("missing value for --part")?;
+                part = Some(parse_part(value)?);
+            }
+            "--input" | "-i" => {
+                let value = args_iter.next().ok_or("missing value for --input")?;
+                input = Variant::File(PathBuf::from(value));
+            }
+            "--example" | "-e" => {
+                let value = args_iter.next().ok_or("missing value for --example")?;
+                input = match value.parse::<u8>() {
+                    Ok(n) if n > 0 => Variant::Example(n),
+                    _ => return Err(format!("invalid example '{}'", value)),
+                };
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(Command::Run {
+        day: day.ok_or("missing day")?,
+        part,
+        input,
+    })
+}
+
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let mut all = false;
+    let mut days = vec![];
+    let mut format = OutputFormat::Table;
+    let mut save = None;
+    let mut compare = None;
+    let mut threshold = DEFAULT_THRESHOLD_PERCENT;
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--format" | "-f" => {
+                let value = args_iter.next().ok_or("missing value for --format")?;
+                format = parse_format(value)?;
+            }
+            "--save" => {
+                let value = args_iter.next().ok_or("missing value for --save")?;
+                save = Some(value.to_string());
+            }
+            "--compare" => {
+                let value = args_iter.next().ok_or("missing value for --compare")?;
+                compare = Some(value.to_string());
+            }
+            "--threshold" => {
+                let value = args_iter.next().ok_or("missing value for --threshold")?;
+                threshold = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|t| *t >= 0.)
+                    .ok_or(format!("invalid threshold '{}'", value))?;
+            }
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+
+    Ok(Command::Bench(BenchOptions {
+        days: select_days(all, days)?,
+        format,
+        save,
+        compare,
+        threshold: threshold / 100.,
+    }))
+}
+
+fn parse_check(args: &[String]) -> Result<Command, String> {
+    let mut all = false;
+    let mut days = vec![];
+    let mut answers = None;
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--answers" => {
+                let value = args_iter.next().ok_or("missing value for --answers")?;
+                answers = Some(value.to_string());
+            }
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+
+    Ok(Command::Check {
+        days: select_days(all, days)?,
+        answers,
+    })
+}
+
+fn select_days(all: bool, days: Vec<u8>) -> Result<Option<Vec<u8>>, String> {
+    match (all, days.is_empty()) {
+        (true, true) => Ok(None),
+        (false, false) => Ok(Some(days)),
+        (true, false) => Err("--all can not be combined with specific days".to_string()),
+        (false, true) => Err("specify days or --all".to_string()),
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day '{}'", value)),
+    }
+}
+
+fn parse_format(value: &str) -> Result<OutputFormat, String> {
+    match value {
+        "table" => Ok(OutputFormat::Table),
+        "json" => Ok(OutputFormat::Json),
+        "csv" => Ok(OutputFormat::Csv),
+        _ => Err(format!("invalid format '{}'", value)),
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part '{}'", value)),
+    }
+}
+
+pub fn main(year: u16, solutions: &[&dyn Solution]) {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let command = parse_args(&args).unwrap_or_else(|e| exit_with_usage(&e));
+
+    match command {
+        Command::Run { day, part, input } => {
+            let solution = find_registered(solutions, day);
+            let raw_input =
+                load(year, day, &input).unwrap_or_else(|e| exit_with_usage(&e.to_string()));
+            let answers = match input {
+                Variant::Real => load_answers(&default_answers_path(year)),
+                _ => Answers::default(),
+            };
+
+            let mut n_errors = 0;
+            for p in part.map_or(vec![1, 2], |p| vec![p]) {
+                let answer = match solve_part(solution, p, &raw_input) {
+                    Ok(answer) => answer,
+                    Err(e) => {
+                        println!("day {:02} part {}: error: {}", day, p, e);
+                        n_errors += 1;
+                        continue;
+                    }
+                };
+                match input {
+                    Variant::Real => {
+                        let verdict = answers.check(day, p, &answer);
+                        println!("day {:02} part {}: {} [{}]", day, p, answer, verdict);
+                    }
+                    _ => println!("day {:02} part {}: {}", day, p, answer),
+                }
+            }
+            if n_errors > 0 {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench(options) => bench(solutions, options),
+        Command::Check { days, answers } => {
+            let answers = answers.unwrap_or_else(|| default_answers_path(year));
+            check(solutions, days, &answers)
+        }
+        Command::New { day } => {
+            let crate_root = crate_dir(year).unwrap_or_else(|e| exit_with_usage(&e.to_string()));
+            match scaffold_day(&crate_root, year, day) {
+                Ok(created) => created
+                    .iter()
+                    .for_each(|p| println!("Created {}", p.display())),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+fn select_solutions<'a>(
+    solutions: &[&'a dyn Solution],
+    days: &Option<Vec<u8>>,
+) -> Vec<&'a dyn Solution> {
+    match days {
+        None => solutions.to_vec(),
+        Some(days) => days
+            .iter()
+            .map(|&d| find_registered(solutions, d))
+            .collect::<Vec<_>>(),
+    }
+}
+
+fn default_answers_path(year: u16) -> String {
+    match crate_dir(year) {
+        Ok(dir) => dir.join(DEFAULT_ANSWERS_PATH).display().to_string(),
+        Err(_) => DEFAULT_ANSWERS_PATH.to_string(),
+    }
+}
+
+fn load_answers(path: &str) -> Answers {
+    Answers::load(path).unwrap_or_else(|e| exit_with_usage(&format!("invalid answers {}", e)))
+}
+
+fn check(solutions: &[&dyn Solution], days: Option<Vec<u8>>, answers_path: &str) {
+    let answers = load_answers(answers_path);
+    let (mut n_pass, mut n_fail, mut n_error, mut n_unknown) = (0, 0, 0, 0);
+    let mut n_skipped = 0;
+
+    for solution in select_solutions(solutions, &days) {
+        let raw_input = match load(solution.year(), solution.day(), &Variant::Real) {
+            Ok(raw_input) => raw_input,
+            Err(e) => {
+                println!("day {:02}: {}", solution.day(), e);
+                n_unknown += 2;
+                continue;
+            }
+        };
+
+        let parts = selected_parts(solution, days.is_none());
+        for p in [1, 2].into_iter().filter(|p| !parts.contains(p)) {
+            println!("day {:02} part {}: skipped", solution.day(), p);
+            n_skipped += 1;
+        }
+        for p in parts {
+            let answer = match solve_part(solution, p, &raw_input) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("day {:02} part {}: error: {}", solution.day(), p, e);
+                    n_error += 1;
+                    continue;
+                }
+            };
+            let verdict = answers.check(solution.day(), p, &answer);
+            match verdict {
+                Verdict::Pass => n_pass += 1,
+                Verdict::Fail { .. } => n_fail += 1,
+                Verdict::Unknown => n_unknown += 1,
+            }
+            let shown_answer = if answer.to_string().contains('\n') {
+                "<multi-line>".to_string()
+            } else {
+                answer.to_string()
+            };
+            println!(
+                "day {:02} part {}: {:<20} {}",
+                solution.day(),
+                p,
+                shown_answer,
+                verdict
+            );
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} errors, {} unknown, {} skipped",
+        n_pass, n_fail, n_error, n_unknown, n_skipped
+    );
+    if n_fail + n_error > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn bench(solutions: &[&dyn Solution], options: BenchOptions) {
+    let selected = select_solutions(solutions, &options.days);
+    let results = benchmark_all(&selected, options.format, options.days.is_none());
+
+    if let Some(path) = &options.save {
+        std::fs::write(path, results_to_json(&results))
+            .unwrap_or_else(|e| exit_with_usage(&format!("unable to write {}: {}", path, e)));
+        eprintln!("Saved {} results to {}", results.len(), path);
+    }
+
+    if let Some(path) = &options.compare {
+        let baseline = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|raw| results_from_json(&raw))
+            .unwrap_or_else(|e| exit_with_usage(&format!("unable to load {}: {}", path, e)));
+
+        let comparisons = compare(&baseline, &results, options.threshold);
+        print_comparison(&comparisons);
+
+        let n_regressed = comparisons.iter().filter(|c| c.regressed).count();
+        if n_regressed > 0 {
+            eprintln!(
+                "{} part(s) regressed by more than {:.1} %",
+                n_regressed,
+                options.threshold * 100.
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+fn find_registered<'a>(solutions: &[&'a dyn Solution], day: u8) -> &'a dyn Solution {
+    find_solution(solutions, day)
+        .unwrap_or_else(|| exit_with_usage(&format!("day {} is not registered", day)))
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    let bin = std::env::args().next().unwrap_or_default();
+    eprintln!("error: {}\n{}", message, USAGE.replace("<bin>", &bin));
+    std::process::exit(2)
+}
+
+#[cfg(test)]
+mod tests_cli {
+    use super::*;
+
+    fn args(raw: &str) -> Vec<String> {
+        raw.split_whitespace().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            parse_args(&args("run 15 --part 2 --input inputs/15_example")),
+            Ok(Command::Run {
+                day: 15,
+                part: Some(2),
+                input: Variant::File(PathBuf::from("inputs/15_example"))
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 10 -e 3")),
+            Ok(Command::Run {
+                day: 10,
+                part: None,
+                input: Variant::Example(3)
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 04")),
+            Ok(Command::Run {
+                day: 4,
+                part: None,
+                input: Variant::Real
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse_args(&args("bench --all")),
+            Ok(Command::Bench(BenchOptions {
+                days: None,
+                format: OutputFormat::Table,
+                save: None,
+                compare: None,
+                threshold: 0.1
+            }))
+        );
+        assert_eq!(
+            parse_args(&args(
+                "bench 11 12 --format csv --save now.json --compare base.json --threshold 25"
+            )),
+            Ok(Command::Bench(BenchOptions {
+                days: Some(vec![11, 12]),
+                format: OutputFormat::Csv,
+                save: Some("now.json".to_string()),
+                compare: Some("base.json".to_string()),
+                threshold: 0.25
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_check() {
+        assert_eq!(
+            parse_args(&args("check --all")),
+            Ok(Command::Check {
+                days: None,
+                answers: None
+            })
+        );
+        assert_eq!(
+            parse_args(&args("check 6 7 --answers old.json")),
+            Ok(Command::Check {
+                days: Some(vec![6, 7]),
+                answers: Some("old.json".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(parse_args(&args("new 07")), Ok(Command::New { day: 7 }));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("fly 11")).is_err());
+        assert!(parse_args(&args("run 26")).is_err());
+        assert!(parse_args(&args("run 11 --part 3")).is_err());
+        assert!(parse_args(&args("run 11 12")).is_err());
+        assert!(parse_args(&args("run 11 --example 0")).is_err());
+        assert!(parse_args(&args("bench")).is_err());
+        assert!(parse_args(&args("bench --all 11")).is_err());
+        assert!(parse_args(&args("bench --all --format xml")).is_err());
+        assert!(parse_args(&args("check")).is_err());
+        assert!(parse_args(&args("new")).is_err());
+        assert!(parse_args(&args("new 1 2")).is_err());
+        assert!(parse_args(&args("bench --all --threshold -5")).is_err());
+    }
+}
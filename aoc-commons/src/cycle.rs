@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a repeatedly stepped state starts looping: the state after
+/// `start + length` steps is again the one after `start` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps reaching the same state as `n` steps.
+    pub fn equivalent_steps(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Steps `initial` until a state repeats, remembering every state by its hash.
+/// Besides the cycle, returns the states visited so far, the `i`-th one after
+/// `i` steps. The states have to repeat eventually, otherwise it never returns.
+pub fn find_cycle<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = states.len() - start;
+            return (Cycle { start, length }, states);
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// The state after `n` steps of `step` from `initial`, skipping over the
+/// repetitions once the states start looping.
+pub fn state_after<S: Clone + Eq + Hash>(initial: S, n: usize, mut step: impl FnMut(&S) -> S) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    while states.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let length = states.len() - start;
+            return states.swap_remove(Cycle { start, length }.equivalent_steps(n));
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+/// Floyd's tortoise and hare: finds the cycle keeping only two states at a
+/// time, at the cost of stepping several times more than `find_cycle`.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // the hare is now a multiple of the cycle length ahead
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Brent's algorithm: like `floyd` it keeps only two states at a time, but
+/// it needs fewer steps.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare `length` steps ahead, they meet at the start of the cycle
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+#[cfg(test)]
+mod tests_cycle {
+    use super::*;
+
+    // 2, 4, 16, 69, 86, 103, 137, 69, ... loops from 69 with length 4
+    fn square(x: &u64) -> u64 {
+        x * x % 187
+    }
+
+    const SQUARE_CYCLE: Cycle = Cycle {
+        start: 3,
+        length: 4,
+    };
+    const FIXED_POINT: Cycle = Cycle {
+        start: 0,
+        length: 1,
+    };
+
+    #[test]
+    fn test_find_cycle() {
+        let (cycle, states) = find_cycle(2, square);
+        assert_eq!(cycle, SQUARE_CYCLE);
+        assert_eq!(states, [2, 4, 16, 69, 86, 103, 137]);
+        assert_eq!(find_cycle(0, square).0, FIXED_POINT);
+
+        assert_eq!(floyd(2, square), SQUARE_CYCLE);
+        assert_eq!(brent(2, square), SQUARE_CYCLE);
+        assert_eq!(floyd(0, square), FIXED_POINT);
+        assert_eq!(brent(0, square), FIXED_POINT);
+    }
+
+    #[test]
+    fn test_state_after() {
+        assert_eq!(state_after(2, 0, square), 2);
+        assert_eq!(state_after(2, 3, square), 69);
+        assert_eq!(state_after(2, 1_000_000_000, square), 86);
+        assert_eq!(state_after(2, 1_000_000_001, square), 103);
+
+        assert_eq!(SQUARE_CYCLE.equivalent_steps(2), 2);
+        assert_eq!(SQUARE_CYCLE.equivalent_steps(9), 5);
+    }
+}
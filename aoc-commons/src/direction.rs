@@ -0,0 +1,230 @@
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+
+use crate::grid::{Grid, GridError, Point};
+
+/// One of the four headings on a grid, where up is towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// Unit vector pointing this way.
+    pub fn vector(self) -> Point {
+        match self {
+            Direction::Up => Point::UP,
+            Direction::Right => Point::RIGHT,
+            Direction::Down => Point::DOWN,
+            Direction::Left => Point::LEFT,
+        }
+    }
+}
+
+/// One of the four headings plus the diagonals between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns by 45°; apply twice for a right angle.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45°; apply twice for a right angle.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Vector towards the neighboring cell, e.g. `(1, -1)` for `UpRight`.
+    pub fn vector(self) -> Point {
+        match self {
+            Direction8::Up => Point::UP,
+            Direction8::UpRight => Point::UP + Point::RIGHT,
+            Direction8::Right => Point::RIGHT,
+            Direction8::DownRight => Point::DOWN + Point::RIGHT,
+            Direction8::Down => Point::DOWN,
+            Direction8::DownLeft => Point::DOWN + Point::LEFT,
+            Direction8::Left => Point::LEFT,
+            Direction8::UpLeft => Point::UP + Point::LEFT,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+/// Accepts `U/D/L/R`, `^v<>` and the compass letters `N/E/S/W`.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::Up),
+            'R' | '>' | 'E' => Ok(Direction::Right),
+            'D' | 'v' | 'S' => Ok(Direction::Down),
+            'L' | '<' | 'W' => Ok(Direction::Left),
+            _ => Err(format!("unknown direction '{}'", c)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("unknown direction '{}'", s)),
+        }
+    }
+}
+
+macro_rules! impl_step {
+    ($($direction:ty),*) => {$(
+        impl From<$direction> for Point {
+            fn from(direction: $direction) -> Point {
+                direction.vector()
+            }
+        }
+
+        impl Add<$direction> for Point {
+            type Output = Point;
+
+            fn add(self, direction: $direction) -> Point {
+                self + direction.vector()
+            }
+        }
+
+        impl AddAssign<$direction> for Point {
+            fn add_assign(&mut self, direction: $direction) {
+                *self += direction.vector();
+            }
+        }
+    )*};
+}
+
+impl_step!(Direction, Direction8);
+
+impl<T> Grid<T> {
+    /// The neighbor of `p` in `direction`, seen through the boundary policy.
+    pub fn step(&self, p: &Point, direction: impl Into<Point>) -> Result<(Point, &T), GridError> {
+        let next = *p + direction.into();
+        Ok((next, self.at(&next)?))
+    }
+}
+
+#[cfg(test)]
+mod tests_direction {
+    use crate::p;
+
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.turn_left().turn_right() == *d
+                && d.turn_right().vector() == d.vector().rotate_clockwise()));
+
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpRight.reverse(), Direction8::DownLeft);
+        assert_eq!(Direction8::from(Direction::Left), Direction8::Left);
+        assert_eq!(Direction8::DownLeft.vector(), p!(-1, 1));
+        assert_eq!(
+            Direction8::ALL.iter().filter(|d| d.is_diagonal()).count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = "URDL^>v<NESW"
+            .chars()
+            .map(|c| Direction::try_from(c).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(parsed, Direction::ALL.repeat(3));
+        assert_eq!("D".parse(), Ok(Direction::Down));
+        assert!("DU".parse::<Direction>().is_err());
+        assert_eq!(
+            Direction::try_from('x'),
+            Err("unknown direction 'x'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let g = Grid::<u8>::parse_digits("12\n34\n").unwrap();
+        let mut p = p!(0, 0);
+
+        assert_eq!(g.step(&p, Direction::Right), Ok((p!(1, 0), &2)));
+        assert_eq!(g.step(&p, Direction8::DownRight), Ok((p!(1, 1), &4)));
+        assert!(g.step(&p, Direction::Up).is_err());
+        p += Direction::Down;
+        assert_eq!(p + Direction::Right, p!(1, 1));
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt;
+
+/// Why a solver could not produce an answer, with the 1-based position in
+/// the input when the failure is tied to one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> SolveError {
+        SolveError {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Reports `token` found in `line`; the column is derived from where the
+    /// token slice lies inside the line.
+    pub fn invalid_token(line: &str, token: &str, expected: &str) -> SolveError {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        SolveError {
+            message: format!("invalid {} '{}'", expected, token),
+            line: None,
+            column: offset
+                .checked_add(token.len())
+                .filter(|end| *end <= line.len())
+                .map(|_| offset + 1),
+        }
+    }
+
+    /// Sets the line unless an inner parser already did.
+    pub fn at_line(mut self, line: usize) -> SolveError {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> SolveError {
+        self.column.get_or_insert(column);
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => (),
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<std::num::ParseIntError> for SolveError {
+    fn from(error: std::num::ParseIntError) -> Self {
+        SolveError::new(format!("invalid number: {}", error))
+    }
+}
+
+/// Parses every non-empty line, tagging errors with their line number.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, SolveError>,
+) -> Result<Vec<T>, SolveError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_line(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_error {
+    use super::*;
+
+    fn parse_pair(line: &str) -> Result<(i32, i32), SolveError> {
+        let (a, b) = line.split_once(',').ok_or(SolveError::new("missing ','"))?;
+        let number = |token: &str| {
+            token
+                .parse::<i32>()
+                .map_err(|_| SolveError::invalid_token(line, token, "number"))
+        };
+        Ok((number(a)?, number(b)?))
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1,2\n\n3,4\n", parse_pair),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+
+        let error = parse_lines("1,2\n3,x4\n", parse_pair).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+        assert_eq!(error.to_string(), "line 2, column 3: invalid number 'x4'");
+
+        let error = parse_lines("1,2\n3;4", parse_pair).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: missing ','");
+    }
+
+    #[test]
+    fn test_position_is_kept() {
+        let error = SolveError::new("oops").at_line(3).at_column(7).at_line(9);
+        assert_eq!(error.to_string(), "line 3, column 7: oops");
+        assert_eq!(SolveError::invalid_token("abc", "zzz", "name").column, None);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::grid::{Grid, Point};
+
+/// Breadth-first distances (in moves) from the nearest of `starts` to every
+/// state reachable through `neighbors`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[&current] + 1;
+        for next in neighbors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Every state reachable from `starts` (included). `neighbors` is called
+/// exactly once per reached state, so it may also have side effects.
+pub fn flood_fill<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut stack = vec![];
+    let mut reached = HashSet::new();
+    for start in starts {
+        if reached.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+
+    while let Some(current) = stack.pop() {
+        for next in neighbors(&current) {
+            if reached.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+    reached
+}
+
+/// Groups `vertices` into connected components, numbered in the order their
+/// first vertex appears. `neighbors` should be symmetric (an undirected graph).
+pub fn connected_components<S, I>(
+    vertices: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut labels = HashMap::new();
+    let mut n_components = 0;
+    for vertex in vertices {
+        if labels.contains_key(&vertex) {
+            continue;
+        }
+        for v in flood_fill([vertex], &mut neighbors) {
+            labels.insert(v, n_components);
+        }
+        n_components += 1;
+    }
+    labels
+}
+
+impl<T> Grid<T> {
+    /// Neighbors are only ever cells of the grid (or of its tiles), so a
+    /// passable `Boundary::Fill` does not spread forever and `Boundary::Wrap`
+    /// connects the opposite edges.
+    fn passable_neighbors<'a>(
+        &'a self,
+        passable: &'a impl Fn(&T) -> bool,
+    ) -> impl Fn(&Point) -> Vec<Point> + 'a {
+        move |p| {
+            self.four_neighborhood_at(p)
+                .into_iter()
+                .filter(|(_, v)| passable(v))
+                .filter_map(|(q, _)| self.walkable(&q))
+                .collect()
+        }
+    }
+
+    /// Four-neighborhood `bfs` distances over the cells satisfying `passable`.
+    pub fn distances(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        passable: impl Fn(&T) -> bool,
+    ) -> HashMap<Point, usize> {
+        bfs(starts, self.passable_neighbors(&passable))
+    }
+
+    /// Four-neighborhood `flood_fill` over the cells satisfying `passable`.
+    pub fn flood_fill(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        passable: impl Fn(&T) -> bool,
+    ) -> HashSet<Point> {
+        flood_fill(starts, self.passable_neighbors(&passable))
+    }
+
+    /// Labels every passable cell with its four-connected component, numbered
+    /// in row-major order; other cells are `None`.
+    pub fn components(&self, passable: impl Fn(&T) -> bool) -> Grid<Option<usize>> {
+        let starts = self.iter().filter(|(_, v)| passable(v)).map(|(p, _)| p);
+        let labels = connected_components(starts, self.passable_neighbors(&passable));
+
+        let cells = self
+            .iter_points()
+            .map(|p| labels.get(&p).copied())
+            .collect();
+        Grid::from_vec(self.width(), self.height(), cells).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests_flood {
+    use crate::grid::Boundary;
+    use crate::p;
+
+    use super::*;
+
+    fn grid(text: &str) -> Grid<bool> {
+        Grid::parse_chars(text, |c| Ok::<_, String>(c == '.')).unwrap()
+    }
+
+    #[test]
+    fn test_graph() {
+        let edges = HashMap::from([(1, vec![2]), (2, vec![1, 3]), (3, vec![2]), (4, vec![])]);
+        let neighbors = |v: &i32| edges[v].clone();
+
+        let distances = bfs([1], neighbors);
+        assert_eq!(distances, HashMap::from([(1, 0), (2, 1), (3, 2)]));
+        assert_eq!(bfs([1, 3], neighbors)[&2], 1);
+        assert_eq!(flood_fill([3], neighbors), HashSet::from([1, 2, 3]));
+
+        let labels = connected_components([4, 1, 2, 3], neighbors);
+        assert_eq!(labels, HashMap::from([(4, 0), (1, 1), (2, 1), (3, 1)]));
+    }
+
+    #[test]
+    fn test_grid() {
+        let g = grid("..#.\n#.#.\n..#.\n");
+
+        let distances = g.distances([p!(0, 0)], |open| *open);
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&p!(0, 2)], 4);
+        assert_eq!(g.flood_fill([p!(3, 2)], |open| *open).len(), 3);
+
+        let components = g.components(|open| *open);
+        assert_eq!(components.at(&p!(1, 2)), Ok(&Some(0)));
+        assert_eq!(components.at(&p!(3, 0)), Ok(&Some(1)));
+        assert_eq!(components.at(&p!(2, 0)), Ok(&None));
+    }
+
+    #[test]
+    fn test_grid_boundaries() {
+        let g = grid(".#.\n###\n.#.\n");
+        assert_eq!(g.flood_fill([p!(0, 0)], |open| *open).len(), 1);
+
+        let wrapped = g.clone().with_boundary(Boundary::Wrap);
+        let reached = wrapped.flood_fill([p!(0, 0)], |open| *open);
+        assert_eq!(
+            reached,
+            HashSet::from([p!(0, 0), p!(2, 0), p!(0, 2), p!(2, 2)])
+        );
+        assert_eq!(wrapped.distances([p!(0, 0)], |open| *open)[&p!(2, 2)], 2);
+        assert_eq!(wrapped.components(|open| *open).at(&p!(2, 2)), Ok(&Some(0)));
+
+        let filled = g.with_boundary(Boundary::Fill(true));
+        assert_eq!(filled.flood_fill([p!(0, 0)], |open| *open).len(), 1);
+        assert_eq!(filled.distances([p!(2, 2)], |open| *open).len(), 1);
+    }
+}
@@ -0,0 +1,569 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+pub trait VertexKey: PartialEq + Eq + Hash + Clone {}
+impl<T: PartialEq + Eq + Hash + Clone> VertexKey for T {}
+
+pub trait Sizable {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Walks along the paths of `Graph::count_paths`: it decides which vertices a
+/// path may step onto and is told about every step taken and taken back, so
+/// it can keep track of the current path in whatever form suits it.
+pub trait PathVisitor<K> {
+    fn can_enter(&self, vertex_key: &K) -> bool;
+    fn enter(&mut self, vertex_key: &K);
+    fn leave(&mut self, vertex_key: &K);
+}
+
+pub trait Graph<K: VertexKey> {
+    fn is_directed(&self) -> bool;
+    fn contains(&self, vertex_key: &K) -> bool;
+    fn add_edge(&mut self, from: &K, to: &K);
+    fn remove_edge(&mut self, from: &K, to: &K) -> bool;
+    fn remove_vertex(&mut self, vertex_key: &K) -> bool;
+    fn iter_vertices<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a;
+    fn iter_adjacents<'a>(&'a self, vertex_key: &K) -> Option<impl Iterator<Item = &'a K>>
+    where
+        K: 'a;
+
+    /// Vertices ordered so that every edge points forward (Kahn's algorithm),
+    /// or `None` if the graph has a cycle.
+    fn topological_sort(&self) -> Option<Vec<K>> {
+        let mut in_degrees: HashMap<&K, usize> = self.iter_vertices().map(|v| (v, 0)).collect();
+        for v in self.iter_vertices() {
+            for adjacent in self.iter_adjacents(v).unwrap() {
+                *in_degrees.get_mut(adjacent).unwrap() += 1;
+            }
+        }
+
+        let mut queue: VecDeque<&K> = in_degrees
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(v, _)| *v)
+            .collect();
+        let mut order = vec![];
+        while let Some(current) = queue.pop_front() {
+            order.push(current.clone());
+            for adjacent in self.iter_adjacents(current).unwrap() {
+                let degree = in_degrees.get_mut(adjacent).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(adjacent);
+                }
+            }
+        }
+
+        if order.len() == in_degrees.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// Strongly connected components (Tarjan's algorithm), each listed once;
+    /// for an undirected graph these are its connected components (which
+    /// `flood::connected_components` also finds without building a graph).
+    fn strongly_connected_components(&self) -> Vec<Vec<K>> {
+        let mut tarjan = Tarjan::default();
+        for v in self.iter_vertices() {
+            if !tarjan.indices.contains_key(v) {
+                tarjan.visit(self, v);
+            }
+        }
+        tarjan.components
+    }
+
+    fn has_cycle(&self) -> bool {
+        if self.is_directed() {
+            return self.topological_sort().is_none();
+        }
+
+        // undirected: some vertex is reachable again other than through the
+        // edge it was discovered by
+        let mut visited: HashSet<&K> = HashSet::new();
+        for root in self.iter_vertices() {
+            if !visited.insert(root) {
+                continue;
+            }
+            let mut stack: Vec<(&K, Option<&K>)> = vec![(root, None)];
+            while let Some((current, parent)) = stack.pop() {
+                for adjacent in self.iter_adjacents(current).unwrap() {
+                    if Some(adjacent) == parent {
+                        continue;
+                    }
+                    if !visited.insert(adjacent) {
+                        return true;
+                    }
+                    stack.push((adjacent, Some(current)));
+                }
+            }
+        }
+        false
+    }
+
+    /// Every path from `from` to `to` which steps onto a vertex only when
+    /// `can_visit(path so far, vertex)` allows it; `to` ends a path. There are
+    /// none when `from` is not in the graph.
+    fn all_paths(
+        &self,
+        from: &K,
+        to: &K,
+        mut can_visit: impl FnMut(&[K], &K) -> bool,
+    ) -> Vec<Vec<K>> {
+        if !self.contains(from) {
+            return vec![];
+        }
+        let mut dfs_stack: Vec<Vec<K>> = vec![vec![from.clone()]];
+        let mut paths = vec![];
+
+        while let Some(path) = dfs_stack.pop() {
+            let last = path.last().unwrap();
+            for adjacent in self.iter_adjacents(last).into_iter().flatten() {
+                if adjacent == to {
+                    let mut complete = path.clone();
+                    complete.push(to.clone());
+                    paths.push(complete);
+                } else if can_visit(&path, adjacent) {
+                    let mut extended = path.clone();
+                    extended.push(adjacent.clone());
+                    dfs_stack.push(extended);
+                }
+            }
+        }
+        paths
+    }
+
+    /// Paths from `from` to `to` which visit every vertex at most once.
+    fn all_simple_paths(&self, from: &K, to: &K) -> Vec<Vec<K>> {
+        self.all_paths(from, to, |path, vertex| !path.contains(vertex))
+    }
+
+    /// Number of the paths `all_paths` would list, walked depth-first one
+    /// step at a time with `visitor` deciding where a path may continue.
+    /// Nothing is collected, so it suits graphs with very many paths.
+    fn count_paths(&self, from: &K, to: &K, visitor: &mut impl PathVisitor<K>) -> usize {
+        let Some(adjacents) = self.iter_adjacents(from) else {
+            return 0;
+        };
+        visitor.enter(from);
+        let mut stack = vec![(from, adjacents)];
+        let mut count = 0;
+
+        while let Some((vertex, adjacents)) = stack.last_mut() {
+            let vertex: &K = vertex;
+            match adjacents.next() {
+                Some(adjacent) if adjacent == to => count += 1,
+                Some(adjacent) => {
+                    if visitor.can_enter(adjacent) {
+                        if let Some(next_adjacents) = self.iter_adjacents(adjacent) {
+                            visitor.enter(adjacent);
+                            stack.push((adjacent, next_adjacents));
+                        }
+                    }
+                }
+                None => {
+                    visitor.leave(vertex);
+                    stack.pop();
+                }
+            }
+        }
+        count
+    }
+}
+
+struct Tarjan<'a, K> {
+    indices: HashMap<&'a K, usize>,
+    low_links: HashMap<&'a K, usize>,
+    stack: Vec<&'a K>,
+    on_stack: HashSet<&'a K>,
+    components: Vec<Vec<K>>,
+}
+
+impl<K> Default for Tarjan<'_, K> {
+    fn default() -> Self {
+        Tarjan {
+            indices: HashMap::new(),
+            low_links: HashMap::new(),
+            stack: vec![],
+            on_stack: HashSet::new(),
+            components: vec![],
+        }
+    }
+}
+
+impl<'a, K: VertexKey> Tarjan<'a, K> {
+    /// Depth-first search from `root` with an explicit stack of the vertices
+    /// being visited and their remaining adjacents, so deep graphs cannot
+    /// overflow the call stack.
+    fn visit<G: Graph<K> + ?Sized>(&mut self, graph: &'a G, root: &'a K) {
+        let adjacents_of = |v: &'a K| graph.iter_adjacents(v).into_iter().flatten();
+        self.open(root);
+        let mut dfs_stack = vec![(root, adjacents_of(root))];
+
+        while let Some((v, adjacents)) = dfs_stack.last_mut() {
+            let v: &'a K = v;
+            if let Some(adjacent) = adjacents.next() {
+                if !self.indices.contains_key(adjacent) {
+                    self.open(adjacent);
+                    dfs_stack.push((adjacent, adjacents_of(adjacent)));
+                } else if self.on_stack.contains(adjacent) {
+                    self.lower_link(v, self.indices[adjacent]);
+                }
+                continue;
+            }
+
+            dfs_stack.pop();
+            if let Some((parent, _)) = dfs_stack.last() {
+                self.lower_link(parent, self.low_links[v]);
+            }
+            if self.low_links[v] == self.indices[v] {
+                self.close_component(v);
+            }
+        }
+    }
+
+    fn open(&mut self, v: &'a K) {
+        let index = self.indices.len();
+        self.indices.insert(v, index);
+        self.low_links.insert(v, index);
+        self.stack.push(v);
+        self.on_stack.insert(v);
+    }
+
+    fn lower_link(&mut self, v: &'a K, low_link: usize) {
+        let current = self.low_links.get_mut(v).unwrap();
+        *current = (*current).min(low_link);
+    }
+
+    /// Pops the component rooted at `v` off the stack.
+    fn close_component(&mut self, v: &K) {
+        let mut component = vec![];
+        while let Some(w) = self.stack.pop() {
+            self.on_stack.remove(w);
+            component.push(w.clone());
+            if w == v {
+                break;
+            }
+        }
+        self.components.push(component);
+    }
+}
+
+/// Adjacency map graph; edges carry a weight of type `W` (nothing by default).
+pub struct SparseGraph<K: VertexKey, W = ()> {
+    adjacents: HashMap<K, HashMap<K, W>>,
+    directed: bool,
+}
+
+impl<K: VertexKey, W: Clone> Default for SparseGraph<K, W> {
+    fn default() -> Self {
+        SparseGraph::new()
+    }
+}
+
+impl<K: VertexKey, W: Clone> SparseGraph<K, W> {
+    pub fn new() -> SparseGraph<K, W> {
+        SparseGraph::directed()
+    }
+
+    pub fn directed() -> SparseGraph<K, W> {
+        SparseGraph {
+            adjacents: HashMap::new(),
+            directed: true,
+        }
+    }
+
+    /// Every edge added to an undirected graph also goes back.
+    pub fn undirected() -> SparseGraph<K, W> {
+        SparseGraph {
+            adjacents: HashMap::new(),
+            directed: false,
+        }
+    }
+
+    pub fn add_vertex(&mut self, vertex_key: &K) {
+        self.adjacents.entry(vertex_key.clone()).or_default();
+    }
+
+    pub fn add_weighted_edge(&mut self, from: &K, to: &K, weight: W) {
+        self.add_vertex(to);
+        if !self.directed {
+            self.adjacents
+                .entry(to.clone())
+                .or_default()
+                .insert(from.clone(), weight.clone());
+        }
+        self.adjacents
+            .entry(from.clone())
+            .or_default()
+            .insert(to.clone(), weight);
+    }
+
+    pub fn weight(&self, from: &K, to: &K) -> Option<&W> {
+        self.adjacents.get(from)?.get(to)
+    }
+
+    pub fn iter_weighted_adjacents<'a>(
+        &'a self,
+        vertex_key: &K,
+    ) -> Option<impl Iterator<Item = (&'a K, &'a W)>> {
+        Some(self.adjacents.get(vertex_key)?.iter())
+    }
+}
+
+impl<K: VertexKey, W> Sizable for SparseGraph<K, W> {
+    fn len(&self) -> usize {
+        self.adjacents.len()
+    }
+}
+
+impl<K: VertexKey, W: Clone + Default> Graph<K> for SparseGraph<K, W> {
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn contains(&self, vertex_key: &K) -> bool {
+        self.adjacents.contains_key(vertex_key)
+    }
+
+    fn add_edge(&mut self, from: &K, to: &K) {
+        self.add_weighted_edge(from, to, W::default());
+    }
+
+    fn remove_edge(&mut self, from: &K, to: &K) -> bool {
+        if !self.directed {
+            if let Some(adjacents) = self.adjacents.get_mut(to) {
+                adjacents.remove(from);
+            }
+        }
+        match self.adjacents.get_mut(from) {
+            Some(adjacents) => adjacents.remove(to).is_some(),
+            None => false,
+        }
+    }
+
+    fn remove_vertex(&mut self, vertex_key: &K) -> bool {
+        if self.adjacents.remove(vertex_key).is_none() {
+            return false;
+        }
+        for adjacents in self.adjacents.values_mut() {
+            adjacents.remove(vertex_key);
+        }
+        true
+    }
+
+    fn iter_vertices<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        self.adjacents.keys()
+    }
+
+    fn iter_adjacents<'a>(&'a self, vertex_key: &K) -> Option<impl Iterator<Item = &'a K>>
+    where
+        K: 'a,
+    {
+        Some(self.adjacents.get(vertex_key)?.keys())
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a str)> for SparseGraph<String> {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut g = SparseGraph::undirected();
+        for pair in iter.into_iter() {
+            g.add_edge(&pair.0.to_string(), &pair.1.to_string());
+        }
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests_graph {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::graph::{Graph, PathVisitor, Sizable, SparseGraph};
+
+    #[test]
+    fn test_sparse_graph_example() {
+        let g: SparseGraph<String> = vec![
+            ("start", "A"),
+            ("start", "b"),
+            ("A", "c"),
+            ("A", "b"),
+            ("b", "d"),
+            ("A", "end"),
+            ("b", "end"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(g.len(), 6);
+        assert_eq!(
+            g.iter_vertices()
+                .map(|x| x.as_str())
+                .collect::<HashSet<_>>(),
+            HashSet::from_iter(vec!["start", "A", "b", "c", "d", "end"].into_iter())
+        );
+    }
+
+    #[test]
+    fn test_weights_and_removal() {
+        let mut directed: SparseGraph<i32, u32> = SparseGraph::directed();
+        directed.add_weighted_edge(&1, &2, 7);
+        directed.add_edge(&2, &3);
+
+        assert_eq!(directed.weight(&1, &2), Some(&7));
+        assert_eq!(directed.weight(&2, &1), None);
+        assert_eq!(directed.weight(&2, &3), Some(&0));
+        assert!(directed.remove_edge(&1, &2));
+        assert!(!directed.remove_edge(&1, &2));
+        assert!(directed.remove_vertex(&3));
+        assert_eq!(directed.len(), 2);
+        assert_eq!(directed.iter_adjacents(&2).unwrap().count(), 0);
+
+        let mut undirected: SparseGraph<char, i64> = SparseGraph::undirected();
+        undirected.add_weighted_edge(&'a', &'b', -3);
+        assert_eq!(undirected.weight(&'b', &'a'), Some(&-3));
+        assert!(undirected.remove_edge(&'b', &'a'));
+        assert_eq!(undirected.iter_weighted_adjacents(&'a').unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_topological_sort_and_cycles() {
+        let mut g: SparseGraph<&str> = SparseGraph::directed();
+        for (from, to) in [
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("pants", "shoes"),
+            ("pants", "jacket"),
+        ] {
+            g.add_edge(&from, &to);
+        }
+
+        let order = g.topological_sort().unwrap();
+        let position = |v: &str| order.iter().position(|o| *o == v).unwrap();
+        assert_eq!(order.len(), 5);
+        assert!(position("shirt") < position("tie") && position("tie") < position("jacket"));
+        assert!(position("pants") < position("shoes"));
+        assert!(!g.has_cycle());
+
+        g.add_edge(&"jacket", &"shirt");
+        assert_eq!(g.topological_sort(), None);
+        assert!(g.has_cycle());
+
+        let mut path: SparseGraph<i32> = SparseGraph::undirected();
+        path.add_edge(&1, &2);
+        path.add_edge(&2, &3);
+        assert!(!path.has_cycle());
+        path.add_edge(&3, &1);
+        assert!(path.has_cycle());
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let mut g: SparseGraph<i32> = SparseGraph::directed();
+        for (from, to) in [(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (6, 6)] {
+            g.add_edge(&from, &to);
+        }
+
+        let mut components = g
+            .strongly_connected_components()
+            .into_iter()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect::<Vec<_>>();
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2, 3], vec![4, 5], vec![6]]);
+    }
+
+    #[test]
+    fn test_all_simple_paths() {
+        let g: SparseGraph<String> =
+            vec![("a", "b"), ("b", "d"), ("a", "c"), ("c", "d"), ("b", "c")]
+                .into_iter()
+                .collect();
+
+        let paths = g.all_simple_paths(&"a".to_string(), &"d".to_string());
+        assert_eq!(paths.len(), 4);
+        assert!(paths.contains(&vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string()
+        ]));
+        assert!(paths
+            .iter()
+            .all(|p| p.iter().collect::<HashSet<_>>().len() == p.len()));
+        assert!(g
+            .all_simple_paths(&"x".to_string(), &"d".to_string())
+            .is_empty());
+    }
+
+    /// Visits every vertex at most once, like `all_simple_paths`.
+    #[derive(Default)]
+    struct SimplePaths {
+        on_path: HashMap<i32, bool>,
+    }
+
+    impl PathVisitor<i32> for SimplePaths {
+        fn can_enter(&self, vertex_key: &i32) -> bool {
+            !self.on_path.get(vertex_key).copied().unwrap_or(false)
+        }
+        fn enter(&mut self, vertex_key: &i32) {
+            self.on_path.insert(*vertex_key, true);
+        }
+        fn leave(&mut self, vertex_key: &i32) {
+            self.on_path.insert(*vertex_key, false);
+        }
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let mut g: SparseGraph<i32> = SparseGraph::undirected();
+        for (from, to) in [(1, 2), (2, 4), (1, 3), (3, 4), (2, 3)] {
+            g.add_edge(&from, &to);
+        }
+
+        let mut visitor = SimplePaths::default();
+        assert_eq!(g.count_paths(&1, &4, &mut visitor), 4);
+        assert!(visitor.on_path.values().all(|on_path| !on_path));
+        assert_eq!(
+            g.count_paths(&1, &4, &mut visitor),
+            g.all_simple_paths(&1, &4).len()
+        );
+        assert_eq!(g.count_paths(&9, &4, &mut visitor), 0);
+    }
+
+    #[test]
+    fn test_deep_strongly_connected_components() {
+        let n = 100_000;
+        let mut g: SparseGraph<i32> = SparseGraph::directed();
+        for v in 0..n {
+            g.add_edge(&v, &((v + 1) % n));
+        }
+        g.add_edge(&0, &n);
+
+        let mut sizes = g
+            .strongly_connected_components()
+            .iter()
+            .map(|c| c.len())
+            .collect::<Vec<_>>();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, n as usize]);
+    }
+}
@@ -0,0 +1,506 @@
+use core::fmt;
+
+use crate::error::SolveError;
+use crate::vec2::Vec2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    DimensionError,
+    AccessError,
+}
+
+/// Cell coordinates; `x` is the column and `y` the row.
+pub type Point = Vec2<i32>;
+
+#[macro_export]
+macro_rules! p {
+    ($x:expr, $y:expr) => {
+        $crate::grid::Point::new($x, $y)
+    };
+}
+
+/// What `Grid::at` and the neighborhoods see outside of the stored cells.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Boundary<T> {
+    /// Points outside the grid are an `AccessError`.
+    Strict,
+    /// Coordinates wrap around the edges, as on a torus.
+    Wrap,
+    /// Every point outside the grid holds this value; it cannot be mutated.
+    Fill(T),
+    /// The grid repeats `columns` times to the right and `rows` times down;
+    /// points beyond the repetitions are an `AccessError`.
+    Tiled { columns: usize, rows: usize },
+}
+
+/// Rectangular grid stored as one row-major buffer.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    boundary: Boundary<T>,
+}
+impl<T> Grid<T> {
+    pub fn from_rows(rows: impl Iterator<Item = Vec<T>>) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows {
+            if *width.get_or_insert(row.len()) != row.len() {
+                return Err(GridError::DimensionError);
+            }
+            cells.extend(row);
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+            boundary: Boundary::Strict,
+        })
+    }
+
+    /// Wraps `cells` laid out row by row.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        if width * height != cells.len() {
+            Err(GridError::DimensionError)
+        } else {
+            Ok(Self {
+                cells,
+                width,
+                height,
+                boundary: Boundary::Strict,
+            })
+        }
+    }
+
+    /// Replaces the boundary policy, `Boundary::Strict` by default.
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn boundary(&self) -> &Boundary<T> {
+        &self.boundary
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether `p` lies within the stored cells, regardless of the boundary.
+    pub fn contains(&self, p: &Point) -> bool {
+        (p.x >= 0 && (p.x as usize) < self.width) && (p.y >= 0 && (p.y as usize) < self.height)
+    }
+
+    /// Which repetition of the grid `p` falls into; `(0, 0)` is the grid itself.
+    pub fn tile_of(&self, p: &Point) -> (i32, i32) {
+        (
+            p.x.div_euclid(self.width.max(1) as i32),
+            p.y.div_euclid(self.height.max(1) as i32),
+        )
+    }
+
+    /// Index of the stored cell that `p` maps to under the boundary policy.
+    fn index(&self, p: &Point) -> Option<usize> {
+        let (w, h) = (self.width as i32, self.height as i32);
+        let wrapped = Point::new(p.x.rem_euclid(w.max(1)), p.y.rem_euclid(h.max(1)));
+        let p = match self.boundary {
+            Boundary::Wrap => wrapped,
+            Boundary::Tiled { columns, rows }
+                if (0..w * columns as i32).contains(&p.x)
+                    && (0..h * rows as i32).contains(&p.y) =>
+            {
+                wrapped
+            }
+            _ => *p,
+        };
+        self.contains(&p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    /// The point `p` stands for when walking the grid: wrapped back into the
+    /// grid under `Boundary::Wrap`, kept within the repetitions under
+    /// `Boundary::Tiled` and `None` outside of the grid otherwise.
+    pub(crate) fn walkable(&self, p: &Point) -> Option<Point> {
+        match self.boundary {
+            Boundary::Wrap => self.index(p).map(|i| self.point(i)),
+            Boundary::Tiled { .. } => self.index(p).map(|_| *p),
+            _ => self.contains(p).then_some(*p),
+        }
+    }
+
+    fn point(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn at(&self, p: &Point) -> Result<&T, GridError> {
+        match (self.index(p), &self.boundary) {
+            (Some(i), _) => Ok(&self.cells[i]),
+            (None, Boundary::Fill(value)) => Ok(value),
+            (None, _) => Err(GridError::AccessError),
+        }
+    }
+
+    pub fn at_mut(&mut self, p: &Point) -> Result<&mut T, GridError> {
+        match self.index(p) {
+            Some(i) => Ok(&mut self.cells[i]),
+            None => Err(GridError::AccessError),
+        }
+    }
+
+    pub fn at_xy(&self, x: usize, y: usize) -> Result<&T, GridError> {
+        self.at(&Point::new(x as i32, y as i32))
+    }
+
+    fn neighbors_at<'a>(&'a self, p: &Point, offsets: &[(i32, i32)]) -> Vec<(Point, &'a T)> {
+        offsets
+            .iter()
+            .map(|(dx, dy)| Point::new(p.x + dx, p.y + dy))
+            .filter_map(|n| Some((n, self.at(&n).ok()?)))
+            .collect::<Vec<_>>()
+    }
+
+    pub fn four_neighborhood_at(&self, p: &Point) -> Vec<(Point, &T)> {
+        self.neighbors_at(p, &[(-1, 0), (1, 0), (0, -1), (0, 1)])
+    }
+    pub fn four_neighborhood_at_xy(&self, x: usize, y: usize) -> Vec<(Point, &T)> {
+        self.four_neighborhood_at(&Point::new(x as i32, y as i32))
+    }
+
+    pub fn eight_neighborhood_at(&self, p: &Point) -> Vec<(Point, &T)> {
+        self.neighbors_at(
+            p,
+            &[
+                (-1, 0),
+                (1, 0),
+                (0, -1),
+                (-1, -1),
+                (1, -1),
+                (0, 1),
+                (-1, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    pub fn eight_neighborhood_xy(&self, x: usize, y: usize) -> Vec<(Point, &T)> {
+        self.eight_neighborhood_at(&Point::new(x as i32, y as i32))
+    }
+
+    pub fn iter_points(&self) -> GridPointIterator {
+        GridPointIterator {
+            index: 0,
+            width: self.width,
+            len: self.len(),
+        }
+    }
+    /// Accessible points of the `width` x `height` window starting at
+    /// `origin`, row by row, with values taken through the boundary policy.
+    pub fn iter_window(
+        &self,
+        origin: Point,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        (0..height as i32)
+            .flat_map(move |dy| (0..width as i32).map(move |dx| origin + Point::new(dx, dy)))
+            .filter_map(|p| Some((p, self.at(&p).ok()?)))
+    }
+
+    pub fn iter_values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.iter_points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, val)| (Point::new((i % width) as i32, (i / width) as i32), val))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        let boundary = match &self.boundary {
+            Boundary::Strict => Boundary::Strict,
+            Boundary::Wrap => Boundary::Wrap,
+            Boundary::Fill(value) => Boundary::Fill(f(value)),
+            Boundary::Tiled { columns, rows } => Boundary::Tiled {
+                columns: *columns,
+                rows: *rows,
+            },
+        };
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            boundary,
+        }
+    }
+
+    /// Position of the first cell, in row-major order, satisfying `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(predicate).map(|i| self.point(i))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses every non-empty line of a character map, one cell per character.
+    /// Errors carry the 1-based row (line) and column of the offending cell.
+    pub fn parse_chars<E: fmt::Display>(
+        input: &str,
+        parse_cell: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, SolveError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row_start = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                let cell = parse_cell(c).map_err(|e| {
+                    SolveError::new(e.to_string())
+                        .at_line(row + 1)
+                        .at_column(column + 1)
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+            let expected_width = *width.get_or_insert(row_width);
+            if row_width != expected_width {
+                return Err(SolveError::new(format!(
+                    "expected {} cells, found {}",
+                    expected_width, row_width
+                ))
+                .at_line(row + 1));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+            boundary: Boundary::Strict,
+        })
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    /// Parses a map of decimal digits such as `"123\n456"`.
+    pub fn parse_digits(input: &str) -> Result<Self, SolveError> {
+        Self::parse_chars(input, |c| match c.to_digit(10) {
+            Some(digit) => Ok(T::from(digit as u8)),
+            None => Err(format!("invalid digit '{}'", c)),
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn full(width: usize, height: usize, fill_value: T) -> Self {
+        Self {
+            cells: vec![fill_value; width * height],
+            width,
+            height,
+            boundary: Boundary::Strict,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Grid{{ w: {}, h: {} }}", self.width(), self.height())
+    }
+}
+
+impl<T> FromIterator<Vec<T>> for Grid<T> {
+    fn from_iter<M: IntoIterator<Item = Vec<T>>>(iter: M) -> Self {
+        Self::from_rows(iter.into_iter()).unwrap()
+    }
+}
+
+pub struct GridPointIterator {
+    index: usize,
+    width: usize,
+    len: usize,
+}
+
+impl Iterator for GridPointIterator {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            None
+        } else {
+            let current_position = Point::new(
+                (self.index % self.width) as i32,
+                (self.index / self.width) as i32,
+            );
+            self.index += 1;
+            Some(current_position)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_grid {
+    use super::*;
+
+    #[test]
+    fn test_create() {
+        let rows = [vec![1, 2, 3], vec![4, 5, 6]];
+
+        let g = Grid::from_rows(rows.iter().cloned()).unwrap();
+        assert_eq!(g.width(), 3);
+        assert_eq!(g.height(), 2);
+        assert_eq!(g.len(), 6);
+        assert_eq!(g, Grid::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap());
+
+        let ragged = vec![vec![1, 2, 3], vec![4, 5]];
+        assert_eq!(
+            Grid::from_rows(ragged.into_iter()).err(),
+            Some(GridError::DimensionError)
+        );
+        assert!(Grid::from_vec(2, 2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let empty_rows: Vec<Vec<i32>> = vec![];
+        let empty_g = Grid::from_rows(empty_rows.iter().cloned()).unwrap();
+        assert!(empty_g.is_empty());
+        assert_eq!(empty_g.len(), 0);
+        assert_eq!(empty_g.iter_points().count(), 0);
+    }
+
+    #[test]
+    fn test_access() {
+        let rows = [vec![1, 2, 3], vec![4, 5, 6]];
+
+        let g = rows.iter().cloned().collect::<Grid<_>>();
+        assert_eq!(g.at_xy(0, 0).unwrap(), &1);
+        assert_eq!(g.at_xy(2, 1).unwrap(), &6);
+        assert_eq!(g.at_xy(100, 100).err().unwrap(), GridError::AccessError);
+        assert_eq!(g.at(&p!(3, 0)).err().unwrap(), GridError::AccessError);
+        assert_eq!(
+            g.four_neighborhood_at_xy(1, 1)
+                .iter()
+                .map(|(_, v)| **v)
+                .collect::<Vec<_>>(),
+            vec![4, 6, 2]
+        );
+        assert_eq!(g.eight_neighborhood_xy(0, 0).len(), 3);
+        assert_eq!(
+            g.iter_values().cloned().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(g.find(|v| *v == 5), Some(p!(1, 1)));
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let parse_cell = |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(format!("unexpected cell '{}'", c)),
+        };
+
+        let g = Grid::parse_chars("#..\n.#.\n\n", parse_cell).unwrap();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.at_xy(1, 1), Ok(&true));
+
+        let error = Grid::parse_chars("#..\n.#.\n..x\n", parse_cell).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 3: unexpected cell 'x'");
+
+        let error = Grid::parse_chars("#..\n.#\n", parse_cell).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected 3 cells, found 2");
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let g = Grid::<i32>::parse_digits("123\n456\n").unwrap();
+        assert_eq!(g, Grid::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap());
+
+        let error = Grid::<u8>::parse_digits("12\n4a").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let g = Grid::<i32>::parse_digits("12\n34\n").unwrap();
+        let values = |g: &Grid<i32>, points: &[Point]| {
+            points
+                .iter()
+                .map(|p| g.at(p).ok().copied())
+                .collect::<Vec<_>>()
+        };
+        let points = [p!(-1, 0), p!(2, 1), p!(3, 3), p!(4, 0)];
+
+        assert_eq!(values(&g, &points), vec![None; 4]);
+        let wrapped = g.clone().with_boundary(Boundary::Wrap);
+        assert_eq!(
+            values(&wrapped, &points),
+            vec![Some(2), Some(3), Some(4), Some(1)]
+        );
+        let filled = g.clone().with_boundary(Boundary::Fill(0));
+        assert_eq!(values(&filled, &points), vec![Some(0); 4]);
+        assert_eq!(filled.eight_neighborhood_xy(0, 0).len(), 8);
+        let tiled = g.clone().with_boundary(Boundary::Tiled {
+            columns: 2,
+            rows: 2,
+        });
+        assert_eq!(values(&tiled, &points), vec![None, Some(3), Some(4), None]);
+        assert_eq!(tiled.tile_of(&p!(3, 2)), (1, 1));
+    }
+
+    #[test]
+    fn test_iter_window() {
+        let g = Grid::<i32>::parse_digits("12\n34\n").unwrap();
+        let window = |g: &Grid<i32>| {
+            g.iter_window(p!(-1, 0), 3, 1)
+                .map(|(p, v)| (p.x, *v))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(window(&g), vec![(0, 1), (1, 2)]);
+        let mut filled = g.with_boundary(Boundary::Fill(9));
+        assert_eq!(window(&filled), vec![(-1, 9), (0, 1), (1, 2)]);
+        assert!(filled.at_mut(&p!(-1, 0)).is_err());
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut g = Grid::full(3, 2, 0);
+        *g.at_mut(&p!(2, 1)).unwrap() = 7;
+        for (p, v) in g.iter_mut() {
+            *v += p.x;
+        }
+        assert_eq!(
+            g.iter().map(|(_, v)| *v).collect::<Vec<_>>(),
+            vec![0, 1, 2, 0, 1, 9]
+        );
+        assert_eq!(g.map(|v| v * 2).at_xy(2, 1), Ok(&18));
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Variant {
+    /// The personal puzzle input.
+    Real,
+    /// The n-th example from the puzzle description, starting at 1.
+    Example(u8),
+    /// A hand-written input stored next to the others under its own name.
+    Custom(String),
+    /// Any file, relative to the working directory.
+    File(PathBuf),
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Variant::Real => write!(f, "real input"),
+            Variant::Example(n) => write!(f, "example {}", n),
+            Variant::Custom(name) => write!(f, "custom input '{}'", name),
+            Variant::File(path) => write!(f, "file {}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    UnknownYear(u16),
+    Missing {
+        year: u16,
+        day: u8,
+        variant: Variant,
+        path: PathBuf,
+    },
+    Unreadable {
+        path: PathBuf,
+        reason: String,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::UnknownYear(year) => write!(f, "no input layout known for year {}", year),
+            InputError::Missing {
+                year,
+                day,
+                variant,
+                path,
+            } => write!(
+                f,
+                "{} of {} day {:02} not found at {}",
+                variant,
+                year,
+                day,
+                path.display()
+            ),
+            InputError::Unreadable { path, reason } => {
+                write!(f, "unable to read {}: {}", path.display(), reason)
+            }
+        }
+    }
+}
+
+/// Where a year keeps its crate and how it names its input files.
+struct Layout {
+    crate_dir: &'static str,
+    inputs_dir: &'static str,
+    real: fn(u8) -> String,
+    example: fn(u8, u8) -> String,
+    custom: fn(u8, &str) -> String,
+}
+
+fn layout(year: u16) -> Result<Layout, InputError> {
+    match year {
+        2021 => Ok(Layout {
+            crate_dir: "2021/aoc-rust",
+            inputs_dir: "inputs",
+            real: |day| format!("{:02}.in", day),
+            example: |day, n| match n {
+                1 => format!("{:02}_example", day),
+                _ => format!("{:02}_example_{}", day, n),
+            },
+            custom: |day, name| format!("{:02}_{}", day, name),
+        }),
+        2022 => Ok(Layout {
+            crate_dir: "2022/rust",
+            inputs_dir: "inputs",
+            real: |day| format!("{:02}_1", day),
+            example: |day, n| format!("{:02}_example_{}", day, n),
+            custom: |day, name| format!("{:02}_{}", day, name),
+        }),
+        2023 => Ok(Layout {
+            crate_dir: "2023/rust",
+            inputs_dir: "src/inputs",
+            real: |day| format!("{:02}.secret", day),
+            example: |day, n| format!("{:02}_example_{}.txt", day, n),
+            custom: |day, name| format!("{:02}_{}.txt", day, name),
+        }),
+        2025 => Ok(Layout {
+            crate_dir: "2025/aoc-2025",
+            inputs_dir: "inputs",
+            real: |day| format!("{:02}.in", day),
+            example: |day, n| match n {
+                1 => format!("{:02}.example", day),
+                _ => format!("{:02}.example_{}", day, n),
+            },
+            custom: |day, name| format!("{:02}.{}", day, name),
+        }),
+        _ => Err(InputError::UnknownYear(year)),
+    }
+}
+
+/// Absolute path of the crate holding the solutions of `year`.
+pub fn crate_dir(year: u16) -> Result<PathBuf, InputError> {
+    let repo_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    Ok(repo_root.join(layout(year)?.crate_dir))
+}
+
+/// Path of an input relative to the crate of `year`.
+pub fn relative_input_path(year: u16, day: u8, variant: &Variant) -> Result<PathBuf, InputError> {
+    let layout = layout(year)?;
+    let file_name = match variant {
+        Variant::Real => (layout.real)(day),
+        Variant::Example(n) => (layout.example)(day, *n),
+        Variant::Custom(name) => (layout.custom)(day, name),
+        Variant::File(path) => return Ok(path.clone()),
+    };
+    Ok(Path::new(layout.inputs_dir).join(file_name))
+}
+
+pub fn input_path(year: u16, day: u8, variant: &Variant) -> Result<PathBuf, InputError> {
+    match variant {
+        Variant::File(path) => Ok(path.clone()),
+        _ => Ok(crate_dir(year)?.join(relative_input_path(year, day, variant)?)),
+    }
+}
+
+pub fn load(year: u16, day: u8, variant: &Variant) -> Result<String, InputError> {
+    let path = input_path(year, day, variant)?;
+    std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => InputError::Missing {
+            year,
+            day,
+            variant: variant.clone(),
+            path,
+        },
+        _ => InputError::Unreadable {
+            path,
+            reason: e.to_string(),
+        },
+    })
+}
+
+/// Test helpers panic with the error, pointing at the calling test.
+#[track_caller]
+fn load_or_panic(year: u16, day: u8, variant: Variant) -> String {
+    match load(year, day, &variant) {
+        Ok(input) => input,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[track_caller]
+pub fn real(year: u16, day: u8) -> String {
+    load_or_panic(year, day, Variant::Real)
+}
+
+#[track_caller]
+pub fn example(year: u16, day: u8, n: u8) -> String {
+    load_or_panic(year, day, Variant::Example(n))
+}
+
+#[track_caller]
+pub fn custom(year: u16, day: u8, name: &str) -> String {
+    load_or_panic(year, day, Variant::Custom(name.to_string()))
+}
+
+#[cfg(test)]
+mod tests_inputs {
+    use super::*;
+
+    #[test]
+    fn test_relative_input_path() {
+        let path = |year, day, variant| relative_input_path(year, day, &variant).unwrap();
+
+        assert_eq!(path(2021, 6, Variant::Real), Path::new("inputs/06.in"));
+        assert_eq!(
+            path(2021, 12, Variant::Custom("even_larger_example".to_string())),
+            Path::new("inputs/12_even_larger_example")
+        );
+        assert_eq!(path(2022, 2, Variant::Real), Path::new("inputs/02_1"));
+        assert_eq!(
+            path(2023, 10, Variant::Example(3)),
+            Path::new("src/inputs/10_example_3.txt")
+        );
+        assert_eq!(
+            path(2025, 4, Variant::Custom("custom".to_string())),
+            Path::new("inputs/04.custom")
+        );
+        assert_eq!(
+            path(2025, 4, Variant::Example(1)),
+            Path::new("inputs/04.example")
+        );
+        assert_eq!(
+            relative_input_path(2019, 1, &Variant::Real),
+            Err(InputError::UnknownYear(2019))
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        assert!(example(2021, 6, 1).starts_with("3,4,3,1,2"));
+
+        let missing = load(2021, 6, &Variant::Example(7)).unwrap_err();
+        assert!(matches!(missing, InputError::Missing { .. }));
+        assert!(missing
+            .to_string()
+            .starts_with("example 7 of 2021 day 06 not found at /"));
+        assert!(missing
+            .to_string()
+            .ends_with("2021/aoc-rust/inputs/06_example_7"));
+    }
+}
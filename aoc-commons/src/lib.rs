@@ -0,0 +1,21 @@
+pub mod answers;
+pub mod benchmark;
+pub mod cli;
+pub mod cycle;
+pub mod direction;
+pub mod error;
+pub mod flood;
+pub mod graph;
+pub mod grid;
+pub mod inputs;
+pub mod lines;
+pub mod memo;
+pub mod render;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod transform;
+pub mod vec2;
+
+pub use error::SolveError;
+pub use solution::{Answer, Solution};
@@ -0,0 +1,259 @@
+use crate::grid::{Grid, Point};
+
+impl<T> Grid<T> {
+    /// Points from `start` (included) in steps of `step` until leaving the
+    /// stored cells; boundary policies are ignored so the walk always ends.
+    fn ray_points(&self, start: Point, step: Point) -> Vec<Point> {
+        assert!(step != Point::ZERO, "a ray needs a non-zero step");
+        let mut points = vec![];
+        let mut p = start;
+        while self.contains(&p) {
+            points.push(p);
+            p += step;
+        }
+        points
+    }
+
+    /// Cells from `start` in steps of `step`, either a `Direction` or a point
+    /// such as `Point::new(1, -1)`, which walks up and to the right.
+    pub fn ray(&self, start: Point, step: impl Into<Point>) -> impl Iterator<Item = (Point, &T)> {
+        self.ray_points(start, step.into())
+            .into_iter()
+            .map(|p| (p, self.at(&p).unwrap()))
+    }
+
+    pub fn ray_mut(
+        &mut self,
+        start: Point,
+        step: impl Into<Point>,
+    ) -> impl Iterator<Item = (Point, &mut T)> {
+        let step = step.into();
+        let points = self.ray_points(start, step);
+        let index = |p: &Point| p.y as usize * self.width() + p.x as usize;
+        let (first, last) = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => (index(first), index(last)),
+            _ => (0, 0),
+        };
+
+        // The cells of a ray are evenly spaced in the row-major buffer.
+        let n = points.len();
+        let stride = (step.y * self.width() as i32 + step.x).unsigned_abs() as usize;
+        let mut cells = self
+            .iter_mut()
+            .skip(first.min(last))
+            .step_by(stride.max(1))
+            .take(n)
+            .collect::<Vec<_>>();
+        if first > last {
+            cells.reverse();
+        }
+        cells.into_iter()
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = (Point, &T)> {
+        self.ray(Point::new(0, y as i32), Point::new(1, 0))
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> impl Iterator<Item = (Point, &mut T)> {
+        self.ray_mut(Point::new(0, y as i32), Point::new(1, 0))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = (Point, &T)> {
+        self.ray(Point::new(x as i32, 0), Point::new(0, 1))
+    }
+
+    pub fn column_mut(&mut self, x: usize) -> impl Iterator<Item = (Point, &mut T)> {
+        self.ray_mut(Point::new(x as i32, 0), Point::new(0, 1))
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        (0..self.height()).map(|y| self.row(y))
+    }
+
+    /// Columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        (0..self.width()).map(|x| self.column(x))
+    }
+
+    /// Diagonals running down and to the right, starting with the one in the
+    /// bottom-left corner and ending with the one in the top-right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        self.diagonal_starts()
+            .into_iter()
+            .map(|start| self.ray(start, DIAGONAL))
+    }
+
+    /// Anti-diagonals running down and to the left, starting with the one in
+    /// the top-left corner and ending with the one in the bottom-right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        self.anti_diagonal_starts()
+            .into_iter()
+            .map(|start| self.ray(start, ANTI_DIAGONAL))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = impl Iterator<Item = (Point, &mut T)>> {
+        let starts = (0..self.height() as i32)
+            .map(|y| Point::new(0, y))
+            .collect();
+        self.lines_mut(starts, Point::new(1, 0))
+    }
+
+    pub fn columns_mut(&mut self) -> impl Iterator<Item = impl Iterator<Item = (Point, &mut T)>> {
+        let starts = (0..self.width() as i32).map(|x| Point::new(x, 0)).collect();
+        self.lines_mut(starts, Point::new(0, 1))
+    }
+
+    pub fn diagonals_mut(&mut self) -> impl Iterator<Item = impl Iterator<Item = (Point, &mut T)>> {
+        self.lines_mut(self.diagonal_starts(), DIAGONAL)
+    }
+
+    pub fn anti_diagonals_mut(
+        &mut self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Point, &mut T)>> {
+        self.lines_mut(self.anti_diagonal_starts(), ANTI_DIAGONAL)
+    }
+
+    fn diagonal_starts(&self) -> Vec<Point> {
+        let (w, h) = (self.width() as i32, self.height() as i32);
+        (0..h)
+            .rev()
+            .map(|y| Point::new(0, y))
+            .chain((1..w).map(|x| Point::new(x, 0)))
+            .collect()
+    }
+
+    fn anti_diagonal_starts(&self) -> Vec<Point> {
+        let (w, h) = (self.width() as i32, self.height() as i32);
+        (0..w)
+            .map(|x| Point::new(x, 0))
+            .chain((1..h).map(|y| Point::new(w - 1, y)))
+            .collect()
+    }
+
+    /// Rays from `starts` that do not share any cell, with mutable cells. Each
+    /// cell is borrowed once from `iter_mut` and moved into its line.
+    fn lines_mut(
+        &mut self,
+        starts: Vec<Point>,
+        step: Point,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Point, &mut T)>> {
+        let lines = starts
+            .into_iter()
+            .map(|start| self.ray_points(start, step))
+            .collect::<Vec<_>>();
+        let width = self.width();
+        let mut cells = self.iter_mut().map(Some).collect::<Vec<_>>();
+
+        lines
+            .into_iter()
+            .map(|line| {
+                line.into_iter()
+                    .map(|p| cells[p.y as usize * width + p.x as usize].take().unwrap())
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+const DIAGONAL: Point = Point::new(1, 1);
+const ANTI_DIAGONAL: Point = Point::new(-1, 1);
+
+#[cfg(test)]
+mod tests_lines {
+    use crate::direction::{Direction, Direction8};
+    use crate::p;
+
+    use super::*;
+
+    fn values<'a>(line: impl Iterator<Item = (Point, &'a char)>) -> String {
+        line.map(|(_, c)| *c).collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let g = Grid::parse_chars("abc\ndef\n", Ok::<_, String>).unwrap();
+
+        assert_eq!(g.rows().map(values).collect::<Vec<_>>(), ["abc", "def"]);
+        assert_eq!(
+            g.columns().map(values).collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            g.diagonals().map(values).collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            g.anti_diagonals().map(values).collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+        assert_eq!(values(g.ray(p!(2, 1), p!(-1, 0))), "fed");
+        assert_eq!(values(g.ray(p!(2, 1), Direction::Left)), "fed");
+        assert_eq!(values(g.ray(p!(0, 1), Direction8::UpRight)), "db");
+        assert_eq!(g.ray(p!(2, 1), p!(-2, -1)).last(), Some((p!(0, 0), &'a')));
+        assert_eq!(values(g.ray(p!(5, 5), p!(1, 0))), "");
+    }
+
+    #[test]
+    fn test_lines_mut() {
+        let mut g = Grid::parse_chars("abc\ndef\n", Ok::<_, String>).unwrap();
+
+        for (_, c) in g.column_mut(1) {
+            *c = c.to_ascii_uppercase();
+        }
+        let reversed = g.ray_mut(p!(2, 1), p!(-1, -1));
+        assert_eq!(
+            reversed.map(|(p, c)| (p, *c)).collect::<Vec<_>>(),
+            [(p!(2, 1), 'f'), (p!(1, 0), 'B')]
+        );
+        for (p, c) in g.row_mut(1) {
+            *c = char::from_digit(p.x as u32, 10).unwrap();
+        }
+        assert_eq!(g.rows().map(values).collect::<Vec<_>>(), ["aBc", "012"]);
+        for (_, c) in g.ray_mut(p!(0, 0), Direction::Down) {
+            *c = '#';
+        }
+        assert_eq!(g.rows().map(values).collect::<Vec<_>>(), ["#Bc", "#12"]);
+    }
+
+    #[test]
+    fn test_lines_of_lines_mut() {
+        let mut g = Grid::parse_chars("abc\ndef\n", Ok::<_, String>).unwrap();
+        let uppercase = |line: &mut dyn Iterator<Item = (Point, &mut char)>| {
+            line.for_each(|(_, c)| *c = c.to_ascii_uppercase())
+        };
+
+        g.rows_mut().take(1).for_each(|mut row| uppercase(&mut row));
+        assert_eq!(g.rows().map(values).collect::<Vec<_>>(), ["ABC", "def"]);
+
+        for (i, column) in g.columns_mut().enumerate() {
+            for (_, c) in column {
+                *c = char::from_digit(i as u32, 10).unwrap();
+            }
+        }
+        assert_eq!(g.rows().map(values).collect::<Vec<_>>(), ["012", "012"]);
+
+        for (i, diagonal) in g.diagonals_mut().enumerate() {
+            for (_, c) in diagonal {
+                *c = (b'a' + i as u8) as char;
+            }
+        }
+        assert_eq!(g.rows().map(values).collect::<Vec<_>>(), ["bcd", "abc"]);
+
+        let anti_diagonals = g
+            .anti_diagonals_mut()
+            .map(|line| line.map(|(p, _)| p).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            anti_diagonals,
+            [
+                vec![p!(0, 0)],
+                vec![p!(1, 0), p!(0, 1)],
+                vec![p!(2, 0), p!(1, 1)],
+                vec![p!(2, 1)]
+            ]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// Cache for memoized recursion. The computation of a missing value gets the
+/// memo back, so it can recurse through it:
+///
+/// ```
+/// use aoc_commons::memo::Memo;
+///
+/// fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     memo.get_or_compute(n, |memo, &n| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(n - 1, memo) + fibonacci(n - 2, memo),
+///     })
+/// }
+///
+/// assert_eq!(fibonacci(90, &mut Memo::new()), 2880067194370816120);
+/// ```
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// The value cached for `key`, or the one `compute` returns for it, which
+    /// is cached from then on.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+
+        let value = compute(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    /// Drops the cached values and resets the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.stats = MemoStats::default();
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl MemoStats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests_memo {
+    use super::*;
+
+    fn paths(p: (u32, u32), memo: &mut Memo<(u32, u32), u64>) -> u64 {
+        memo.get_or_compute(p, |memo, &(x, y)| match (x, y) {
+            (0, _) | (_, 0) => 1,
+            _ => paths((x - 1, y), memo) + paths((x, y - 1), memo),
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+
+        assert_eq!(paths((2, 2), &mut memo), 6);
+        assert_eq!(memo.get(&(1, 1)), Some(&2));
+        assert_eq!(memo.len(), 8);
+        assert_eq!(memo.stats(), MemoStats { hits: 1, misses: 8 });
+        assert_eq!(
+            memo.stats().to_string(),
+            "1 hits, 8 misses (11.1% hit rate)"
+        );
+
+        assert_eq!(paths((16, 16), &mut memo), 601080390);
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats().hit_rate(), 0.0);
+    }
+}
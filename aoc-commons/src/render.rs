@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::grid::{Grid, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+struct Overlay {
+    points: HashSet<Point>,
+    symbol: Option<char>,
+    color: Option<Color>,
+}
+
+/// Prints a grid one row per line through `Display`. Overlays are applied in
+/// the order they were added, so later ones win where they overlap.
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
+    overlays: Vec<Overlay>,
+}
+
+impl<'a, T, F: Fn(&T) -> char> Render<'a, T, F> {
+    fn overlay(
+        mut self,
+        points: impl IntoIterator<Item = Point>,
+        symbol: Option<char>,
+        color: Option<Color>,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            points: points.into_iter().collect(),
+            symbol,
+            color,
+        });
+        self
+    }
+
+    /// Draws `symbol` instead of the cell at each of `points`.
+    pub fn mark(self, points: impl IntoIterator<Item = Point>, symbol: char) -> Self {
+        self.overlay(points, Some(symbol), None)
+    }
+
+    /// Colors the cells at `points` with ANSI escape codes.
+    pub fn paint(self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        self.overlay(points, None, Some(color))
+    }
+}
+
+impl<T, F: Fn(&T) -> char> fmt::Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (p, value) in self.grid.iter() {
+            let mut symbol = (self.cell)(value);
+            let mut color = None;
+            for overlay in self.overlays.iter().filter(|o| o.points.contains(&p)) {
+                symbol = overlay.symbol.unwrap_or(symbol);
+                color = overlay.color.or(color);
+            }
+
+            match color {
+                Some(color) => write!(f, "\x1b[{}m{}\x1b[0m", color.ansi_code(), symbol)?,
+                None => write!(f, "{}", symbol)?,
+            }
+            if p.x as usize == self.grid.width() - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Renders every cell as the character returned by `cell`, e.g.
+    /// `println!("{}", grid.render(|c| *c).mark(path, '#'))`.
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> Render<'_, T, F> {
+        Render {
+            grid: self,
+            cell,
+            overlays: vec![],
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (p, value) in self.iter() {
+            write!(f, "{}", value)?;
+            if p.x as usize == self.width() - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests_render {
+    use crate::p;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let g = Grid::<u8>::parse_digits("123\n456\n").unwrap();
+        assert_eq!(g.to_string(), "123\n456\n");
+        assert_eq!(Grid::<u8>::full(0, 0, 0).to_string(), "");
+    }
+
+    #[test]
+    fn test_render() {
+        let g = Grid::parse_chars("#..\n.#.\n", |c| Ok::<_, String>(c == '#')).unwrap();
+        let render = || g.render(|wall| if *wall { '#' } else { '.' });
+
+        assert_eq!(render().to_string(), "#..\n.#.\n");
+        assert_eq!(
+            render()
+                .mark([p!(1, 0), p!(2, 1)], 'o')
+                .mark([p!(2, 1)], 'x')
+                .to_string(),
+            "#o.\n.#x\n"
+        );
+        assert_eq!(
+            render().paint([p!(0, 1)], Color::Red).to_string(),
+            "#..\n\x1b[31m.\x1b[0m#.\n"
+        );
+    }
+}
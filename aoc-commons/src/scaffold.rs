@@ -0,0 +1,207 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::inputs::{relative_input_path, Variant};
+
+pub const TEMPLATE_PATH: &str = "src/template.rs";
+pub const MAIN_PATH: &str = "src/main.rs";
+
+/// Input stubs created for a new day, relative to the crate root.
+fn input_stub_paths(year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    [Variant::Real, Variant::Example(1)]
+        .iter()
+        .map(|variant| relative_input_path(year, day, variant).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// Replaces `DAY_NUMBER` with the plain and `DAY` with the zero-padded day number.
+fn render_template(template: &str, day: u8) -> String {
+    template
+        .replace("DAY_NUMBER", &day.to_string())
+        .replace("DAY", &format!("{:02}", day))
+}
+
+/// Inserts `new_line` among the lines recognized by `day_of`, keeping them
+/// ordered by day when possible; falls back to the line after `anchor`.
+fn insert_day_line(
+    source: &str,
+    new_line: &str,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+    anchor: impl Fn(&str) -> bool,
+) -> Result<String, String> {
+    let mut lines = source.lines().map(|l| l.to_string()).collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, day_of(l.trim())?)))
+        .collect::<Vec<_>>();
+
+    if days.iter().any(|(_, d)| *d == day) {
+        return Err(format!("'{}' is already present", new_line.trim()));
+    }
+
+    let index = match days.iter().find(|(_, d)| *d > day) {
+        Some((i, _)) => *i,
+        None => match days.last() {
+            Some((i, _)) => i + 1,
+            None => {
+                lines
+                    .iter()
+                    .position(|l| anchor(l.trim()))
+                    .ok_or(format!("no place found for '{}'", new_line.trim()))?
+                    + 1
+            }
+        },
+    };
+    lines.insert(index, new_line.to_string());
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn parse_day_suffix(text: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    text.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+fn register_in_main(main_source: &str, day: u8) -> Result<String, String> {
+    let with_mod = insert_day_line(
+        main_source,
+        &format!("mod day_{:02};", day),
+        day,
+        |l| parse_day_suffix(l, "mod day_", ";"),
+        |l| l.starts_with("mod "),
+    )?;
+    insert_day_line(
+        &with_mod,
+        &format!("    &day_{:02}::Solver,", day),
+        day,
+        |l| parse_day_suffix(l, "&day_", "::Solver,"),
+        |l| l.starts_with("const SOLUTIONS"),
+    )
+}
+
+/// Creates `src/day_XX.rs` from the year's template, registers it in
+/// `src/main.rs` and creates empty input stubs. Returns the created files.
+pub fn scaffold_day(crate_root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let day_path = crate_root.join(format!("src/day_{:02}.rs", day));
+    if day_path.exists() {
+        return Err(format!("{} already exists", day_path.display()));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| format!("unable to read {}: {}", path.display(), e))
+    };
+    let write = |path: &Path, content: &str| {
+        fs::write(path, content).map_err(|e| format!("unable to write {}: {}", path.display(), e))
+    };
+
+    let stubs = input_stub_paths(year, day)?;
+    let template = read(&crate_root.join(TEMPLATE_PATH))?;
+    let main_path = crate_root.join(MAIN_PATH);
+    let main_source = register_in_main(&read(&main_path)?, day)?;
+
+    write(&day_path, &render_template(&template, day))?;
+    write(&main_path, &main_source)?;
+    let mut created = vec![day_path];
+
+    for stub in stubs {
+        let stub_path = crate_root.join(stub);
+        if stub_path.exists() {
+            continue;
+        }
+        if let Some(dir) = stub_path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("unable to create {}: {}", dir.display(), e))?;
+        }
+        write(&stub_path, "")?;
+        created.push(stub_path);
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests_scaffold {
+    use super::*;
+
+    const MAIN: &str = "mod commons;
+mod day_01;
+mod day_04;
+
+const SOLUTIONS: &[&dyn Solution] = &[
+    &day_01::Solver,
+    &day_04::Solver,
+];
+";
+
+    #[test]
+    fn test_render_template() {
+        assert_eq!(
+            render_template("solution!(2021, DAY_NUMBER, ..);\ninputs/DAY.in", 7),
+            "solution!(2021, 7, ..);\ninputs/07.in"
+        );
+    }
+
+    #[test]
+    fn test_register_in_main() {
+        let registered = register_in_main(MAIN, 3).unwrap();
+        assert_eq!(
+            registered,
+            "mod commons;
+mod day_01;
+mod day_03;
+mod day_04;
+
+const SOLUTIONS: &[&dyn Solution] = &[
+    &day_01::Solver,
+    &day_03::Solver,
+    &day_04::Solver,
+];
+"
+        );
+
+        let appended = register_in_main(MAIN, 12).unwrap();
+        assert!(appended.contains("mod day_04;\nmod day_12;\n"));
+        assert!(appended.contains("    &day_04::Solver,\n    &day_12::Solver,\n];"));
+
+        assert!(register_in_main(MAIN, 4).is_err());
+    }
+
+    #[test]
+    fn test_register_first_day() {
+        let registered = register_in_main(
+            "mod utils;\n\nconst SOLUTIONS: &[&dyn Solution] = &[\n];\n",
+            1,
+        )
+        .unwrap();
+        assert_eq!(
+            registered,
+            "mod utils;\nmod day_01;\n\nconst SOLUTIONS: &[&dyn Solution] = &[\n    &day_01::Solver,\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join(TEMPLATE_PATH), "// day DAY").unwrap();
+        fs::write(root.join(MAIN_PATH), MAIN).unwrap();
+
+        let created = scaffold_day(&root, 2025, 5).unwrap();
+        assert_eq!(
+            created,
+            vec![
+                root.join("src/day_05.rs"),
+                root.join("inputs/05.in"),
+                root.join("inputs/05.example")
+            ]
+        );
+        assert_eq!(fs::read_to_string(&created[0]).unwrap(), "// day 05");
+        assert!(scaffold_day(&root, 2025, 5).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cheapest route found by a search, `states` running from the start to the
+/// goal (both included).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Dijkstra's search from `start` to the first state satisfying `is_goal`.
+/// `successors` lists the states reachable in one move with its cost; costs
+/// must not be negative and `C::default()` is taken as zero.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, is_goal, |_| C::default())
+}
+
+/// Like `dijkstra`, but states are explored in the order of their cost plus
+/// `heuristic`, which must never overestimate the cost left to a goal.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // States are referred to by their index so the heap does not need `S: Ord`.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut costs = vec![C::default()];
+    let mut predecessors: Vec<Option<usize>> = vec![None];
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            continue; // already reached more cheaply
+        }
+        let state = states[index].clone();
+        if is_goal(&state) {
+            let mut path = vec![];
+            let mut cursor = Some(index);
+            while let Some(i) = cursor {
+                path.push(states[i].clone());
+                cursor = predecessors[i];
+            }
+            path.reverse();
+            return Some(Path { cost, states: path });
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let next_index = match indices.get(&next) {
+                Some(&i) if costs[i] <= next_cost => continue,
+                Some(&i) => {
+                    costs[i] = next_cost;
+                    predecessors[i] = Some(index);
+                    i
+                }
+                None => {
+                    indices.insert(next.clone(), states.len());
+                    states.push(next.clone());
+                    costs.push(next_cost);
+                    predecessors.push(Some(index));
+                    states.len() - 1
+                }
+            };
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests_search {
+    use crate::grid::{Grid, Point};
+    use crate::p;
+
+    use super::*;
+
+    fn successors(grid: &Grid<u32>) -> impl Fn(&Point) -> Vec<(Point, u32)> + '_ {
+        |p| {
+            grid.four_neighborhood_at(p)
+                .into_iter()
+                .filter(|(_, cost)| **cost != 0)
+                .map(|(q, cost)| (q, *cost))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let g = Grid::parse_digits("131\n191\n111\n").unwrap();
+        let end = p!(2, 2);
+
+        let path = dijkstra(p!(0, 0), successors(&g), |p| *p == end).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(
+            path.states,
+            [p!(0, 0), p!(0, 1), p!(0, 2), p!(1, 2), p!(2, 2)]
+        );
+
+        let start = dijkstra(p!(0, 0), successors(&g), |_| true).unwrap();
+        assert_eq!(start.cost, 0);
+        assert_eq!(start.states, [p!(0, 0)]);
+    }
+
+    #[test]
+    fn test_astar() {
+        let g = Grid::parse_digits("1111\n1001\n1111\n").unwrap();
+        let end = p!(3, 1);
+
+        let path = astar(
+            p!(0, 1),
+            successors(&g),
+            |p| *p == end,
+            |p| p.manhattan(end) as u32,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+        assert_eq!(
+            dijkstra(p!(0, 1), successors(&g), |p| *p == end).map(|p| p.cost),
+            Some(5)
+        );
+
+        let walled = Grid::parse_digits("101\n101\n").unwrap();
+        assert_eq!(dijkstra(p!(0, 0), successors(&walled), |p| p.x == 2), None);
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::error::SolveError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Number(i64::try_from(value).expect("Answer does not fit into i64"))
+            }
+        }
+        )*
+    };
+}
+
+impl_answer_from_number!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// What a day's `first_part`/`second_part` may return: a plain answer or a
+/// `Result` of one.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, SolveError>;
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(
+        impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<Answer, SolveError> {
+                Ok(self.into())
+            }
+        }
+        )*
+    };
+}
+
+impl_into_answer!(i8, i16, i32, i64, u8, u16, u32, u64, usize, String, &str);
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Result<Answer, SolveError> {
+        Ok(self)
+    }
+}
+
+impl<T: IntoAnswer> IntoAnswer for Result<T, SolveError> {
+    fn into_answer(self) -> Result<Answer, SolveError> {
+        self.and_then(IntoAnswer::into_answer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+pub trait Solution {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn first_part(&self, input: &str) -> Result<Answer, SolveError>;
+    fn second_part(&self, input: &str) -> Result<Answer, SolveError>;
+
+    /// Parts `check --all` and `bench --all` leave out, e.g. because they do
+    /// not finish yet; naming the day explicitly still runs them.
+    fn skipped_parts(&self) -> &'static [u8] {
+        &[]
+    }
+
+    fn part(&self, part: u8, input: &str) -> Result<Answer, SolveError> {
+        match part {
+            1 => self.first_part(input),
+            2 => self.second_part(input),
+            _ => Err(SolveError::new(format!("there is no part {}", part))),
+        }
+    }
+}
+
+/// Declares `Solver` implementing `Solution` in the calling day module;
+/// `skip = [2]` lists parts left out when running all days.
+#[macro_export]
+macro_rules! solution {
+    ($year:expr, $day:expr, $first_part:path, $second_part:path) => {
+        $crate::solution!($year, $day, $first_part, $second_part, skip = []);
+    };
+    ($year:expr, $day:expr, $first_part:path, $second_part:path, skip = [$($part:expr),*]) => {
+        pub struct Solver;
+
+        impl $crate::Solution for Solver {
+            fn year(&self) -> u16 {
+                $year
+            }
+
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn first_part(&self, input: &str) -> Result<$crate::Answer, $crate::error::SolveError> {
+                $crate::solution::IntoAnswer::into_answer($first_part(input))
+            }
+
+            fn second_part(
+                &self,
+                input: &str,
+            ) -> Result<$crate::Answer, $crate::error::SolveError> {
+                $crate::solution::IntoAnswer::into_answer($second_part(input))
+            }
+
+            fn skipped_parts(&self) -> &'static [u8] {
+                &[$($part),*]
+            }
+        }
+    };
+}
+
+/// The parts to run of `solution`, without its skipped ones when running
+/// `all` days.
+pub fn selected_parts(solution: &dyn Solution, all: bool) -> Vec<u8> {
+    [1, 2]
+        .into_iter()
+        .filter(|p| !all || !solution.skipped_parts().contains(p))
+        .collect()
+}
+
+/// Runs one part, turning a panic of a solver that does not report errors
+/// yet into a `SolveError` so that the remaining days still run.
+pub fn solve_part(solution: &dyn Solution, part: u8, input: &str) -> Result<Answer, SolveError> {
+    catch_unwind(AssertUnwindSafe(|| solution.part(part, input))).unwrap_or_else(|payload| {
+        let reason = payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| "unknown reason".to_string());
+        Err(SolveError::new(format!("panicked: {}", reason)))
+    })
+}
+
+pub fn find_solution<'a>(solutions: &[&'a dyn Solution], day: u8) -> Option<&'a dyn Solution> {
+    solutions.iter().find(|s| s.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests_solution {
+    use super::*;
+
+    mod day_01 {
+        pub fn first_part(input: &str) -> i32 {
+            input.lines().count() as i32
+        }
+
+        pub fn second_part(input: &str) -> String {
+            input.to_uppercase()
+        }
+
+        solution!(2042, 1, first_part, second_part);
+    }
+
+    mod day_02 {
+        use crate::error::SolveError;
+
+        pub fn first_part(input: &str) -> Result<i32, SolveError> {
+            Ok(input.trim().parse::<i32>()?)
+        }
+
+        pub fn second_part(input: &str) -> i32 {
+            input.trim().parse().unwrap()
+        }
+
+        solution!(2042, 2, first_part, second_part, skip = [2]);
+    }
+
+    #[test]
+    fn test_solution_macro() {
+        let solutions: &[&dyn Solution] = &[&day_01::Solver, &day_02::Solver];
+        let s = find_solution(solutions, 1).unwrap();
+
+        assert_eq!((s.year(), s.day()), (2042, 1));
+        assert_eq!(s.first_part("a\nb"), Ok(Answer::Number(2)));
+        assert_eq!(s.part(2, "ab"), Ok(Answer::Text("AB".to_string())));
+        assert!(s.part(3, "ab").is_err());
+        assert!(find_solution(solutions, 3).is_none());
+
+        assert_eq!(s.skipped_parts(), &[] as &[u8]);
+        assert_eq!(selected_parts(s, true), [1, 2]);
+        let skipping = find_solution(solutions, 2).unwrap();
+        assert_eq!(skipping.skipped_parts(), [2]);
+        assert_eq!(selected_parts(skipping, true), [1]);
+        assert_eq!(selected_parts(skipping, false), [1, 2]);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let s = &day_02::Solver;
+        assert_eq!(solve_part(s, 1, "42\n"), Ok(Answer::Number(42)));
+        assert_eq!(
+            solve_part(s, 1, "4x").unwrap_err().to_string(),
+            "invalid number: invalid digit found in string"
+        );
+        assert!(solve_part(s, 2, "4x")
+            .unwrap_err()
+            .to_string()
+            .starts_with("panicked: "));
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(1738377086345_i64).to_string(), "1738377086345");
+        assert_eq!(Answer::from("#..#").to_string(), "#..#");
+    }
+}
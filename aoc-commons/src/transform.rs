@@ -0,0 +1,213 @@
+use crate::grid::{Boundary, Grid, GridError, Point};
+
+impl<T: Clone> Grid<T> {
+    /// Builds a `width` x `height` grid whose cell at `(x, y)` is copied from
+    /// `source(x, y)` of this one.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        boundary: Boundary<T>,
+        source: impl Fn(i32, i32) -> Point,
+    ) -> Grid<T> {
+        let cells = (0..height as i32)
+            .flat_map(|y| (0..width as i32).map(move |x| (x, y)))
+            .map(|(x, y)| self.at(&source(x, y)).unwrap().clone())
+            .collect();
+        Grid::from_vec(width, height, cells)
+            .unwrap()
+            .with_boundary(boundary)
+    }
+
+    /// Boundary of the grid with its axes swapped.
+    fn swapped_boundary(&self) -> Boundary<T> {
+        match self.boundary() {
+            Boundary::Tiled { columns, rows } => Boundary::Tiled {
+                columns: *rows,
+                rows: *columns,
+            },
+            boundary => boundary.clone(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(
+            self.height(),
+            self.width(),
+            self.swapped_boundary(),
+            |x, y| Point::new(y, x),
+        )
+    }
+
+    /// Rotates by 90° so that the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let h = self.height() as i32;
+        self.remap(
+            self.height(),
+            self.width(),
+            self.swapped_boundary(),
+            |x, y| Point::new(y, h - 1 - x),
+        )
+    }
+
+    /// Rotates by 90° so that the top row becomes the left column.
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        let w = self.width() as i32;
+        self.remap(
+            self.height(),
+            self.width(),
+            self.swapped_boundary(),
+            |x, y| Point::new(w - 1 - y, x),
+        )
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let w = self.width() as i32;
+        self.remap(
+            self.width(),
+            self.height(),
+            self.boundary().clone(),
+            |x, y| Point::new(w - 1 - x, y),
+        )
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let h = self.height() as i32;
+        self.remap(
+            self.width(),
+            self.height(),
+            self.boundary().clone(),
+            |x, y| Point::new(x, h - 1 - y),
+        )
+    }
+}
+
+/// Borrowed rectangle of a grid; points are relative to its top-left corner.
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        (p.x >= 0 && (p.x as usize) < self.width) && (p.y >= 0 && (p.y as usize) < self.height)
+    }
+
+    pub fn at(&self, p: &Point) -> Result<&'a T, GridError> {
+        if self.contains(p) {
+            self.grid.at(&(self.origin + *p))
+        } else {
+            Err(GridError::AccessError)
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        (0..self.height as i32)
+            .flat_map(|y| (0..self.width as i32).map(move |x| Point::new(x, y)))
+            .map(|p| (p, self.at(&p).unwrap()))
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    /// Copies the viewed cells into a grid of their own.
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_vec(
+            self.width,
+            self.height,
+            self.iter().map(|(_, v)| v.clone()).collect(),
+        )
+        .unwrap()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Views the `width` x `height` rectangle starting at `origin`, which must
+    /// lie within the stored cells.
+    pub fn view(
+        &self,
+        origin: Point,
+        width: usize,
+        height: usize,
+    ) -> Result<GridView<'_, T>, GridError> {
+        let fits =
+            |start: i32, len: usize, total: usize| start >= 0 && start as usize + len <= total;
+        if fits(origin.x, width, self.width()) && fits(origin.y, height, self.height()) {
+            Ok(GridView {
+                grid: self,
+                origin,
+                width,
+                height,
+            })
+        } else {
+            Err(GridError::DimensionError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_transform {
+    use crate::p;
+
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse_chars(text, Ok::<_, String>).unwrap()
+    }
+
+    #[test]
+    fn test_rotations() {
+        let g = grid("ab\ncd\nef\n");
+
+        assert_eq!(g.transpose(), grid("ace\nbdf\n"));
+        assert_eq!(g.rotate_clockwise(), grid("eca\nfdb\n"));
+        assert_eq!(g.rotate_counterclockwise(), grid("bdf\nace\n"));
+        assert_eq!(g.rotate_clockwise().rotate_counterclockwise(), g);
+        assert_eq!(
+            g.rotate_clockwise().rotate_clockwise(),
+            g.flip_horizontal().flip_vertical()
+        );
+        assert_eq!(g.flip_horizontal(), grid("ba\ndc\nfe\n"));
+        assert_eq!(g.flip_vertical(), grid("ef\ncd\nab\n"));
+
+        let tiled = g.with_boundary(Boundary::Tiled {
+            columns: 1,
+            rows: 3,
+        });
+        assert_eq!(
+            tiled.transpose().boundary(),
+            &Boundary::Tiled {
+                columns: 3,
+                rows: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let g = grid("abc\ndef\nghi\n");
+        let view = g.view(p!(1, 1), 2, 2).unwrap();
+
+        assert_eq!((view.width(), view.height()), (2, 2));
+        assert_eq!(view.at(&p!(0, 0)), Ok(&'e'));
+        assert_eq!(view.at(&p!(2, 0)), Err(GridError::AccessError));
+        assert_eq!(view.to_grid(), grid("ef\nhi\n"));
+        assert_eq!(view.iter().count(), 4);
+
+        assert!(g.view(p!(2, 0), 2, 1).is_err());
+        assert!(g.view(p!(-1, 0), 1, 1).is_err());
+        assert_eq!(g.view(p!(3, 3), 0, 0).unwrap().iter().count(), 0);
+    }
+}
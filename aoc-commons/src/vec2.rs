@@ -0,0 +1,149 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// 2D vector or position. `y` grows downwards, as rows do in puzzle inputs,
+/// so `UP` is `(0, -1)` and clockwise turns `RIGHT` into `DOWN`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, scalar: T) -> Vec2<T> {
+        Vec2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Vec2<T> {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Vec2<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Vec2<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+macro_rules! impl_signed_vec2 {
+    ($($t:ty),*) => {$(
+        impl Vec2<$t> {
+            pub const ZERO: Vec2<$t> = Vec2::new(0, 0);
+            pub const UP: Vec2<$t> = Vec2::new(0, -1);
+            pub const DOWN: Vec2<$t> = Vec2::new(0, 1);
+            pub const LEFT: Vec2<$t> = Vec2::new(-1, 0);
+            pub const RIGHT: Vec2<$t> = Vec2::new(1, 0);
+
+            pub fn manhattan(self, other: Vec2<$t>) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs()
+            }
+
+            pub fn chebyshev(self, other: Vec2<$t>) -> $t {
+                (self.x - other.x).abs().max((self.y - other.y).abs())
+            }
+
+            /// Rotates by 90° around the origin.
+            pub fn rotate_clockwise(self) -> Vec2<$t> {
+                Vec2::new(-self.y, self.x)
+            }
+
+            pub fn rotate_counterclockwise(self) -> Vec2<$t> {
+                Vec2::new(self.y, -self.x)
+            }
+        }
+    )*};
+}
+
+impl_signed_vec2!(i32, i64, isize);
+
+#[cfg(test)]
+mod tests_vec2 {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Vec2::new(3, -2);
+        let b = Vec2::from((1, 4));
+
+        assert_eq!(a + b, Vec2::new(4, 2));
+        assert_eq!(a - b, Vec2::new(2, -6));
+        assert_eq!(a * 3, Vec2::new(9, -6));
+        assert_eq!(-a, Vec2::new(-3, 2));
+
+        let mut c = a;
+        c += b;
+        c -= Vec2::new(0, 1);
+        assert_eq!(<(i32, i32)>::from(c), (4, 1));
+        assert_eq!(Vec2::new(0.5, 2.0) * 2.0, Vec2::new(1.0, 4.0));
+        assert_eq!(c.to_string(), "(4, 1)");
+    }
+
+    #[test]
+    fn test_distances_and_rotation() {
+        let a = Vec2::<i64>::new(3, -2);
+
+        assert_eq!(a.manhattan(Vec2::<i64>::ZERO), 5);
+        assert_eq!(a.chebyshev(Vec2::new(1, 4)), 6);
+        assert_eq!(Vec2::<i32>::RIGHT.rotate_clockwise(), Vec2::<i32>::DOWN);
+        assert_eq!(Vec2::<i32>::UP.rotate_counterclockwise(), Vec2::<i32>::LEFT);
+        assert_eq!(
+            a.rotate_clockwise().rotate_clockwise(),
+            -a,
+            "two quarter turns"
+        );
+    }
+}